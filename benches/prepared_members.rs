@@ -0,0 +1,79 @@
+//! Measures the cost of preparing a ring for verification, and of validating a proof against a
+//! prepared ring compared to `BandersnatchVrfVerifiable::validate`.
+//!
+//! Run with `cargo bench`.
+
+use std::ops::Range;
+use std::time::Instant;
+
+use ark_scale::ArkScale;
+use ark_serialize::CanonicalDeserialize;
+use verifiable::ring_vrf_impl::{
+	bandersnatch_vrfs::ring::StaticVerifierKey, BandersnatchVrfVerifiable9, PreparedMembers,
+	RingParams,
+};
+use verifiable::GenerateVerifiable;
+
+const ONCHAIN_VK: &[u8] = include_bytes!("../src/ring-data/zcash-9.vk");
+const OFFCHAIN_PK: &[u8] = include_bytes!("../src/ring-data/zcash-9.pk");
+
+const ITERATIONS: u32 = 20;
+
+fn main() {
+	RingParams::from_bytes(OFFCHAIN_PK)
+		.expect("shipped prover key is valid")
+		.install()
+		.unwrap_or_else(|_| panic!("no ring params are installed yet"));
+
+	let secrets: Vec<_> = (0..100)
+		.map(|i| BandersnatchVrfVerifiable9::new_secret([i as u8; 32]))
+		.collect();
+	let members: Vec<_> = secrets
+		.iter()
+		.map(BandersnatchVrfVerifiable9::member_from_secret)
+		.collect();
+
+	let vk = StaticVerifierKey::deserialize_uncompressed_unchecked(ONCHAIN_VK).unwrap();
+	let get_many =
+		|range: Range<usize>| Ok(vk.lag_g1[range].iter().cloned().map(ArkScale).collect());
+	let mut inter = BandersnatchVrfVerifiable9::start_members();
+	BandersnatchVrfVerifiable9::push_members(&mut inter, members.iter().cloned(), get_many)
+		.unwrap();
+	let ring = BandersnatchVrfVerifiable9::finish_members(inter);
+
+	let commitment =
+		BandersnatchVrfVerifiable9::open(&members[0], members.clone().into_iter()).unwrap();
+	let (proof, alias) =
+		BandersnatchVrfVerifiable9::create(commitment, &secrets[0], b"context", b"message")
+			.unwrap();
+
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		let _ = PreparedMembers::new(ring.clone()).unwrap();
+	}
+	println!(
+		"* Prepare members: {} us",
+		(Instant::now() - start).as_micros() / ITERATIONS as u128
+	);
+
+	let prepared = PreparedMembers::new(ring.clone()).unwrap();
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		assert!(prepared.is_valid(&proof, b"context", &alias, b"message"));
+	}
+	println!(
+		"* Validate with prepared members: {} us",
+		(Instant::now() - start).as_micros() / ITERATIONS as u128
+	);
+
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		assert!(BandersnatchVrfVerifiable9::is_valid(
+			&proof, &ring, b"context", &alias, b"message"
+		));
+	}
+	println!(
+		"* Validate: {} us",
+		(Instant::now() - start).as_micros() / ITERATIONS as u128
+	);
+}
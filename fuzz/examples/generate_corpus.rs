@@ -0,0 +1,99 @@
+//! Writes a seed corpus of valid inputs for each of the fuzz targets into `corpus/`.
+//!
+//! Usage (from the `fuzz` directory): `cargo run --release --example generate_corpus`
+
+use std::fs;
+use std::path::Path;
+
+use parity_scale_codec::Encode;
+use verifiable::demo_impls::{Simple, Trivial};
+use verifiable::ring_vrf_impl::BandersnatchVrfVerifiable9;
+use verifiable::{GenerateVerifiable, Receipt};
+use verifiable_fuzz::{fixed_members, members, secrets, CONTEXT};
+
+fn write(target: &str, name: &str, data: &[u8]) {
+	let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+		.join("corpus")
+		.join(target);
+	fs::create_dir_all(&dir).expect("corpus directory is writable");
+	fs::write(dir.join(name), data).expect("corpus file is writable");
+}
+
+/// Seeds for `verifiable_fuzz::verify` of a demo implementation.
+fn demo_seeds<T: GenerateVerifiable<StaticChunk = ()>>(target: &str) {
+	let secrets: Vec<_> = (0..4u8).map(|i| T::new_secret([i; 32])).collect();
+	let members: Vec<_> = secrets.iter().map(T::member_from_secret).collect();
+	let mut inter = T::start_members();
+	T::push_members(&mut inter, members.iter().cloned(), |range| {
+		Ok(range.map(|_| ()).collect())
+	})
+	.expect("members can be pushed");
+	let ring = T::finish_members(inter);
+
+	let message = b"message";
+	let commitment = T::open(&members[0], members.iter().cloned()).expect("member can open");
+	let (proof, alias) =
+		T::create(commitment, &secrets[0], CONTEXT, message).expect("member can prove");
+	let mut data = vec![0];
+	(&ring, &proof, CONTEXT.to_vec(), alias).encode_to(&mut data);
+	data.extend_from_slice(message);
+	write(target, "validate", &data);
+}
+
+fn main() {
+	demo_seeds::<Trivial>("verify_trivial");
+	demo_seeds::<Simple>("verify_simple");
+
+	let secrets = secrets();
+	let members = members();
+	let ring = fixed_members();
+	let message = b"message";
+
+	let commitment = BandersnatchVrfVerifiable9::open(&members[0], members.iter().cloned())
+		.expect("member can open");
+	let encoded_commitment = commitment.encode();
+	let (proof, alias) =
+		BandersnatchVrfVerifiable9::create(commitment, &secrets[0], CONTEXT, message)
+			.expect("member can prove");
+	let signature =
+		BandersnatchVrfVerifiable9::sign(&secrets[0], message).expect("member can sign");
+
+	let mut data = vec![0];
+	(ring, &proof, CONTEXT.to_vec(), alias).encode_to(&mut data);
+	data.extend_from_slice(message);
+	write("verify_bandersnatch", "validate", &data);
+	let mut data = vec![1];
+	(&members[0], &signature).encode_to(&mut data);
+	data.extend_from_slice(message);
+	write("verify_bandersnatch", "signature", &data);
+
+	let mut data = proof.encode();
+	data.extend_from_slice(message);
+	write("validate_bandersnatch", "proof", &data);
+
+	let seeds: [(&str, Vec<u8>); 6] = [
+		("members", ring.encode()),
+		(
+			"intermediate",
+			BandersnatchVrfVerifiable9::start_members().encode(),
+		),
+		("member", members[0].encode()),
+		("commitment", encoded_commitment),
+		("proof", proof.encode()),
+		("signature", signature.encode()),
+	];
+	for (i, (name, encoded)) in seeds.iter().enumerate() {
+		let mut data = vec![i as u8];
+		data.extend_from_slice(encoded);
+		write("decode_bandersnatch", name, &data);
+	}
+
+	let receipt = Receipt::<BandersnatchVrfVerifiable9>::create(
+		&secrets[0],
+		members.iter().cloned(),
+		CONTEXT,
+		message.to_vec(),
+	)
+	.expect("member can create a receipt");
+	write("decode_receipt", "receipt", &receipt.encode());
+}
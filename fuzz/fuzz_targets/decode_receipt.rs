@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use parity_scale_codec::Decode;
+use verifiable::ring_vrf_impl::BandersnatchVrfVerifiable9;
+use verifiable::Receipt;
+
+fuzz_target!(|data: &[u8]| {
+	let Ok(receipt) = Receipt::<BandersnatchVrfVerifiable9>::decode(&mut &data[..]) else {
+		return;
+	};
+	let _ = receipt.verify(verifiable_fuzz::fixed_members(), verifiable_fuzz::CONTEXT);
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use parity_scale_codec::Decode;
+use verifiable::ring_vrf_impl::BandersnatchVrfVerifiable9;
+use verifiable::GenerateVerifiable;
+
+fuzz_target!(|data: &[u8]| {
+	let mut input = data;
+	let Ok(proof) = <BandersnatchVrfVerifiable9 as GenerateVerifiable>::Proof::decode(&mut input)
+	else {
+		return;
+	};
+	// The rest of the input is the message.
+	let _ = verifiable_fuzz::fixed_ring().validate(&proof, verifiable_fuzz::CONTEXT, input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use verifiable::ring_vrf_impl::BandersnatchVrfVerifiable9;
+
+fuzz_target!(|data: &[u8]| {
+	verifiable_fuzz::verify::<BandersnatchVrfVerifiable9>(data);
+});
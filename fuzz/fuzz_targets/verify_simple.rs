@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use verifiable::demo_impls::Simple;
+
+fuzz_target!(|data: &[u8]| {
+	verifiable_fuzz::verify::<Simple>(data);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use verifiable::demo_impls::Trivial;
+
+fuzz_target!(|data: &[u8]| {
+	verifiable_fuzz::verify::<Trivial>(data);
+});
@@ -0,0 +1,116 @@
+//! Shared bodies and fixtures of the fuzz targets.
+
+use std::sync::OnceLock;
+
+use ark_scale::ArkScale;
+use ark_serialize::CanonicalDeserialize;
+use parity_scale_codec::{Decode, Encode};
+use verifiable::ring_vrf_impl::{
+	bandersnatch_vrfs::{ring::StaticVerifierKey, SecretKey},
+	BandersnatchVrfVerifiable9, MembersCommitment, PreparedMembers,
+};
+use verifiable::GenerateVerifiable;
+
+/// Verifier key of the domain of `BandersnatchVrfVerifiable9`.
+const ONCHAIN_VK: &[u8] = include_bytes!("../../src/ring-data/zcash-9.vk");
+
+/// Context of the proofs validated against the fixed ring.
+pub const CONTEXT: &[u8] = b"VerifiableFuzzContext";
+
+/// Number of members of the fixed ring.
+pub const MEMBERS: u8 = 8;
+
+/// Secrets of the members of the fixed ring.
+pub fn secrets() -> Vec<SecretKey> {
+	(0..MEMBERS)
+		.map(|i| BandersnatchVrfVerifiable9::new_secret([i; 32]))
+		.collect()
+}
+
+/// Members of the fixed ring.
+pub fn members() -> Vec<<BandersnatchVrfVerifiable9 as GenerateVerifiable>::Member> {
+	secrets()
+		.iter()
+		.map(BandersnatchVrfVerifiable9::member_from_secret)
+		.collect()
+}
+
+/// A ring of `members`, built once.
+pub fn fixed_ring() -> &'static PreparedMembers {
+	static RING: OnceLock<PreparedMembers> = OnceLock::new();
+	RING.get_or_init(|| {
+		let vk = StaticVerifierKey::deserialize_uncompressed_unchecked(ONCHAIN_VK)
+			.expect("embedded verifier key is valid");
+		let mut inter = BandersnatchVrfVerifiable9::start_members();
+		BandersnatchVrfVerifiable9::push_members(&mut inter, members().into_iter(), |range| {
+			Ok(vk.lag_g1[range].iter().cloned().map(ArkScale).collect())
+		})
+		.expect("fixed ring can be built");
+		PreparedMembers::new(BandersnatchVrfVerifiable9::finish_members(inter))
+			.expect("fixed ring is of a known domain")
+	})
+}
+
+/// The `MembersCommitment` of `fixed_ring`.
+pub fn fixed_members() -> &'static MembersCommitment {
+	fixed_ring().members()
+}
+
+/// Feed arbitrary `Members`, `Proof`, `Member` and `Signature` encodings to the verification
+/// functions of `T`, none of which may panic.
+pub fn verify<T: GenerateVerifiable>(data: &[u8]) {
+	let Some((&selector, mut input)) = data.split_first() else {
+		return;
+	};
+	match selector % 2 {
+		0 => {
+			let Ok(members) = T::Members::decode(&mut input) else {
+				return;
+			};
+			let Ok(proof) = T::Proof::decode(&mut input) else {
+				return;
+			};
+			let Ok((context, alias)) = <(Vec<u8>, [u8; 32])>::decode(&mut input) else {
+				return;
+			};
+			// The rest of the input is the message.
+			let _ = T::validate(&proof, &members, &context, input);
+			let _ = T::is_valid(&proof, &members, &context, &alias, input);
+		}
+		_ => {
+			let Ok(member) = T::Member::decode(&mut input) else {
+				return;
+			};
+			let Ok(signature) = T::Signature::decode(&mut input) else {
+				return;
+			};
+			let _ = T::verify_signature(&signature, input, &member);
+		}
+	}
+}
+
+/// Decode an arbitrary encoding of one of the associated types of `T`, chosen by the first byte
+/// of `data`. Anything which decodes must survive an encoding round-trip.
+pub fn decode<T: GenerateVerifiable>(data: &[u8]) {
+	fn round_trip<V: Decode + Encode>(mut input: &[u8]) {
+		let Ok(value) = V::decode(&mut input) else {
+			return;
+		};
+		let encoded = value.encode();
+		let decoded = V::decode(&mut &encoded[..]).expect("encoded values decode");
+		assert_eq!(decoded.encode(), encoded);
+	}
+
+	let Some((&selector, input)) = data.split_first() else {
+		return;
+	};
+	match selector % 7 {
+		0 => round_trip::<T::Members>(input),
+		1 => round_trip::<T::Intermediate>(input),
+		2 => round_trip::<T::Member>(input),
+		3 => round_trip::<T::Commitment>(input),
+		4 => round_trip::<T::Proof>(input),
+		5 => round_trip::<T::Signature>(input),
+		_ => round_trip::<T::StaticChunk>(input),
+	}
+}
@@ -0,0 +1,35 @@
+//! Writes the test vectors of `BandersnatchVrfVerifiable` replayed by its tests.
+//!
+//! Usage: `cargo run --bin generate-test-vectors [OUTPUT]`
+
+use ark_serialize::CanonicalDeserialize;
+use parity_scale_codec::Encode;
+use verifiable::ring_vrf_impl::{
+	bandersnatch_vrfs::ring::StaticVerifierKey, test_vectors, RingParams,
+};
+
+const ONCHAIN_VK: &[u8] = include_bytes!("../ring-data/zcash-9.vk");
+const OFFCHAIN_PK: &[u8] = include_bytes!("../ring-data/zcash-9.pk");
+const DEFAULT_OUTPUT: &str = concat!(
+	env!("CARGO_MANIFEST_DIR"),
+	"/src/ring-data/test-vectors-zcash-9.scale"
+);
+const MEMBERS: u8 = 8;
+
+fn main() {
+	let output = std::env::args()
+		.nth(1)
+		.unwrap_or_else(|| DEFAULT_OUTPUT.into());
+
+	RingParams::from_bytes(OFFCHAIN_PK)
+		.expect("shipped prover key is valid")
+		.install()
+		.unwrap_or_else(|_| panic!("no ring params are installed yet"));
+	let vk = StaticVerifierKey::deserialize_uncompressed_unchecked(ONCHAIN_VK)
+		.expect("embedded verifier key is valid");
+	let vectors = test_vectors::generate::<9>(MEMBERS, |range| Ok(vk.lag_g1[range].to_vec()))
+		.expect("vectors can be generated");
+
+	std::fs::write(&output, vectors.encode()).expect("output is writable");
+	println!("Wrote {} vectors to {}", vectors.vectors.len(), output);
+}
@@ -0,0 +1,97 @@
+//! Generates ring parameters for private deployments, or checks a pair of them.
+//!
+//! Usage:
+//! - `verifiable-setup generate LOG_DOMAIN SEED OUT_DIR`: parameters from a 32 byte hex `SEED`,
+//!   for testing only;
+//! - `verifiable-setup import LOG_DOMAIN TRANSCRIPT OUT_DIR`: parameters from a ceremony
+//!   transcript laid out as described by `Srs::from_transcript`;
+//! - `verifiable-setup check PK VK`: check that the prover and verifier keys match.
+//!
+//! `generate` and `import` write `srs-LOG_DOMAIN.pk` and `srs-LOG_DOMAIN.vk` laid out like the
+//! `zcash-*` files in `ring-data`, the KZG verifier key (serialized uncompressed) as
+//! `srs-LOG_DOMAIN.kzg-vk` and the empty `MembersSet` (SCALE encoded) as
+//! `srs-LOG_DOMAIN.empty-ring`.
+
+use std::path::Path;
+use std::process::exit;
+
+use ark_serialize::CanonicalSerialize;
+use parity_scale_codec::Encode;
+use verifiable::setup::{check, Srs};
+
+const USAGE: &str = "\
+Usage:
+	verifiable-setup generate LOG_DOMAIN SEED OUT_DIR
+	verifiable-setup import LOG_DOMAIN TRANSCRIPT OUT_DIR
+	verifiable-setup check PK VK";
+
+fn fail(message: impl std::fmt::Display) -> ! {
+	eprintln!("{message}");
+	exit(1)
+}
+
+fn read(path: &str) -> Vec<u8> {
+	std::fs::read(path).unwrap_or_else(|e| fail(format!("Cannot read {path}: {e}")))
+}
+
+fn parse_log_domain(arg: &str) -> u32 {
+	arg.parse()
+		.unwrap_or_else(|_| fail(format!("Invalid LOG_DOMAIN: {arg}")))
+}
+
+fn parse_seed(arg: &str) -> [u8; 32] {
+	let bytes = arg.strip_prefix("0x").unwrap_or(arg).as_bytes();
+	if bytes.len() != 64 {
+		fail("SEED must be 32 bytes in hex");
+	}
+	let mut seed = [0u8; 32];
+	for (byte, pair) in seed.iter_mut().zip(bytes.chunks(2)) {
+		let pair = std::str::from_utf8(pair).unwrap_or_default();
+		*byte =
+			u8::from_str_radix(pair, 16).unwrap_or_else(|_| fail("SEED must be 32 bytes in hex"));
+	}
+	seed
+}
+
+fn write(srs: &Srs, out_dir: &str) {
+	let out_dir = Path::new(out_dir);
+	std::fs::create_dir_all(out_dir).unwrap_or_else(|e| fail(format!("Cannot create output: {e}")));
+	let mut kzg_vk = Vec::new();
+	srs.kzg_vk()
+		.serialize_uncompressed(&mut kzg_vk)
+		.expect("serialization into a Vec cannot fail; qed");
+	let files = [
+		("pk", srs.prover_key()),
+		("vk", srs.verifier_key()),
+		("kzg-vk", kzg_vk),
+		("empty-ring", srs.empty_members().encode()),
+	];
+	for (extension, data) in files {
+		let path = out_dir.join(format!("srs-{}.{extension}", srs.log_domain()));
+		std::fs::write(&path, data)
+			.unwrap_or_else(|e| fail(format!("Cannot write {}: {e}", path.display())));
+		println!("Wrote {}", path.display());
+	}
+}
+
+fn main() {
+	let args: Vec<String> = std::env::args().skip(1).collect();
+	let args: Vec<&str> = args.iter().map(String::as_str).collect();
+	match args[..] {
+		["generate", log_domain, seed, out_dir] => {
+			let srs = Srs::from_seed(parse_log_domain(log_domain), parse_seed(seed))
+				.unwrap_or_else(|e| fail(format!("Cannot generate parameters: {e:?}")));
+			write(&srs, out_dir);
+		}
+		["import", log_domain, transcript, out_dir] => {
+			let srs = Srs::from_transcript(parse_log_domain(log_domain), &read(transcript))
+				.unwrap_or_else(|e| fail(format!("Cannot import transcript: {e:?}")));
+			write(&srs, out_dir);
+		}
+		["check", pk, vk] => match check(&read(pk), &read(vk)) {
+			Ok(log_domain) => println!("Parameters match, for the domain of size 2^{log_domain}"),
+			Err(e) => fail(format!("Parameters do not match: {e:?}")),
+		},
+		_ => fail(USAGE),
+	}
+}
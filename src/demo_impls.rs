@@ -1,13 +1,63 @@
 use super::*;
 use bounded_collections::{BoundedVec, ConstU32};
-use schnorrkel::{signing_context, ExpansionMode, MiniSecretKey, PublicKey};
+use schnorrkel::{
+	context::SigningTranscript,
+	signing_context,
+	vrf::{VRFPreOut, VRFProof},
+	ExpansionMode, Keypair, MiniSecretKey, PublicKey,
+};
 
 // Example impls:
 
+/// Replace `who` at `index` of a plain set of members with `new`.
+fn replace_in_set<S>(
+	set: &mut BoundedVec<[u8; 32], S>,
+	index: usize,
+	who: [u8; 32],
+	new: [u8; 32],
+) -> Result<(), VerifiableError> {
+	let slot = set
+		.get_mut(index)
+		.ok_or(VerifiableError::MemberIndexOutOfRange)?;
+	if *slot != who {
+		return Err(VerifiableError::NotMember);
+	}
+	*slot = new;
+	Ok(())
+}
+
+/// Swap-remove `who` at `index` of a plain set of members, checking `last` is its last member.
+fn remove_from_set<S>(
+	set: &mut BoundedVec<[u8; 32], S>,
+	index: usize,
+	who: [u8; 32],
+	last: [u8; 32],
+) -> Result<(), VerifiableError> {
+	if index >= set.len() {
+		return Err(VerifiableError::MemberIndexOutOfRange);
+	}
+	if set[index] != who || set.last() != Some(&last) {
+		return Err(VerifiableError::NotMember);
+	}
+	set.swap_remove(index);
+	Ok(())
+}
+
+const TRIVIAL_CON: &[u8] = b"verifiable-trivial";
+
+/// Hash of `data` under `label`, as used by `Trivial`.
+fn trivial_hash(label: &'static [u8], data: impl Encode) -> [u8; 32] {
+	let mut transcript = data.using_encoded(|b| signing_context(TRIVIAL_CON).bytes(b));
+	let mut hash = [0u8; 32];
+	transcript.challenge_bytes(label, &mut hash);
+	hash
+}
+
 /// Totally insecure Anonymizer: Member and Secret are both the same `[u8; 32]` and the proof is
-/// just the identity. The `alias` is always the identity and the root is just a `Vec<Self::Member>`.
-/// Verification just checks that the proof and the alias are the same and that the alias exists
-/// in the "root" (just a Vec).
+/// just the identity together with a hash binding it to the context and message. The `alias` is
+/// a hash of the identity and the context and the root is just a `Vec<Self::Member>`.
+/// Verification just checks that the identity exists in the "root" (just a Vec) and that the
+/// binding hash matches the context and message.
 pub struct Trivial;
 impl GenerateVerifiable for Trivial {
 	type Members = BoundedVec<Self::Member, ConstU32<1024>>;
@@ -15,7 +65,8 @@ impl GenerateVerifiable for Trivial {
 	type Member = [u8; 32];
 	type Secret = [u8; 32];
 	type Commitment = (Self::Member, Vec<Self::Member>);
-	type Proof = [u8; 32];
+	/// The member and the hash binding it to the context and message.
+	type Proof = (Self::Member, [u8; 32]);
 	type Signature = [u8; 32];
 	type StaticChunk = ();
 
@@ -23,12 +74,40 @@ impl GenerateVerifiable for Trivial {
 		BoundedVec::new()
 	}
 
+	fn member_count(inter: &Self::Intermediate) -> usize {
+		inter.len()
+	}
+
+	fn capacity() -> usize {
+		<Self::Intermediate>::bound()
+	}
+
 	fn push_member(
 		inter: &mut Self::Intermediate,
 		who: Self::Member,
 		_lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
-	) -> Result<(), ()> {
-		inter.try_push(who).map_err(|_| ())
+	) -> Result<(), VerifiableError> {
+		inter.try_push(who).map_err(|_| VerifiableError::RingFull)
+	}
+
+	fn replace_member(
+		inter: &mut Self::Intermediate,
+		index: usize,
+		who: Self::Member,
+		new: Self::Member,
+		_lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
+	) -> Result<(), VerifiableError> {
+		replace_in_set(inter, index, who, new)
+	}
+
+	fn remove_member(
+		inter: &mut Self::Intermediate,
+		index: usize,
+		who: Self::Member,
+		last: Self::Member,
+		_lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
+	) -> Result<(), VerifiableError> {
+		remove_from_set(inter, index, who, last)
 	}
 	fn finish_members(inter: Self::Intermediate) -> Self::Members {
 		inter
@@ -45,10 +124,10 @@ impl GenerateVerifiable for Trivial {
 	fn open(
 		member: &Self::Member,
 		members: impl Iterator<Item = Self::Member>,
-	) -> Result<Self::Commitment, ()> {
+	) -> Result<Self::Commitment, VerifiableError> {
 		let set = members.collect::<Vec<_>>();
 		if !set.contains(member) {
-			return Err(());
+			return Err(VerifiableError::NotMember);
 		}
 		Ok((member.clone(), set))
 	}
@@ -56,32 +135,46 @@ impl GenerateVerifiable for Trivial {
 	fn create(
 		(member, _): Self::Commitment,
 		secret: &Self::Secret,
-		_context: &[u8],
-		_message: &[u8],
-	) -> Result<(Self::Proof, Alias), ()> {
+		context: &[u8],
+		message: &[u8],
+	) -> Result<(Self::Proof, Alias), VerifiableError> {
 		if &member != secret {
-			return Err(());
+			return Err(VerifiableError::SecretMismatch);
 		}
-		Ok(((secret.clone()), secret.clone()))
+		let binding = trivial_hash(b"binding", (&member, context, message));
+		let alias = trivial_hash(b"alias", (&member, context));
+		Ok(((member, binding), alias))
 	}
 
 	fn validate(
-		proof: &Self::Proof,
+		(member, binding): &Self::Proof,
 		members: &Self::Members,
-		_context: &[u8],
-		_message: &[u8],
-	) -> Result<Alias, ()> {
-		if members.contains(&proof) {
-			Ok(proof.clone())
-		} else {
-			Err(())
+		context: &[u8],
+		message: &[u8],
+	) -> Result<Alias, VerifiableError> {
+		if !members.contains(member) {
+			return Err(VerifiableError::NotMember);
+		}
+		if binding != &trivial_hash(b"binding", (member, context, message)) {
+			return Err(VerifiableError::InvalidProof);
 		}
+		Ok(trivial_hash(b"alias", (member, context)))
 	}
 }
 
 const SIG_CON: &[u8] = b"verifiable";
+const SIGN_CON: &[u8] = b"verifiable-signature";
+const VRF_CON: &[u8] = b"verifiable-alias";
+const ALIAS_CON: &[u8] = b"verifiable-alias-output";
+
+fn keypair(secret: &[u8; 32]) -> Keypair {
+	let secret = MiniSecretKey::from_bytes(&secret[..]).unwrap();
+	secret.expand_to_keypair(ExpansionMode::Ed25519)
+}
 
-/// Example impl of `Verifiable` which uses Schnorrkel. This doesn't anonymise anything.
+/// Example impl of `Verifiable` which uses Schnorrkel. This doesn't anonymise anything: the proof
+/// contains the member. The `alias` is however derived from an sr25519 VRF over the context, so
+/// (like with the Ring-VRF) it differs between contexts and is unlinkable without the proof.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
 pub struct Simple;
 impl GenerateVerifiable for Simple {
@@ -90,20 +183,49 @@ impl GenerateVerifiable for Simple {
 	type Member = [u8; 32];
 	type Secret = [u8; 32];
 	type Commitment = (Self::Member, Vec<Self::Member>);
-	type Proof = ([u8; 64], Alias);
-	type Signature = [u8; 32];
+	/// The member, the VRF pre-output and the VRF proof.
+	type Proof = (Self::Member, [u8; 32], [u8; 64]);
+	type Signature = [u8; 64];
 	type StaticChunk = ();
 
 	fn start_members() -> Self::Intermediate {
 		BoundedVec::new()
 	}
 
+	fn member_count(inter: &Self::Intermediate) -> usize {
+		inter.len()
+	}
+
+	fn capacity() -> usize {
+		<Self::Intermediate>::bound()
+	}
+
 	fn push_member(
 		inter: &mut Self::Intermediate,
 		who: Self::Member,
 		_lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
-	) -> Result<(), ()> {
-		inter.try_push(who).map_err(|_| ())
+	) -> Result<(), VerifiableError> {
+		inter.try_push(who).map_err(|_| VerifiableError::RingFull)
+	}
+
+	fn replace_member(
+		inter: &mut Self::Intermediate,
+		index: usize,
+		who: Self::Member,
+		new: Self::Member,
+		_lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
+	) -> Result<(), VerifiableError> {
+		replace_in_set(inter, index, who, new)
+	}
+
+	fn remove_member(
+		inter: &mut Self::Intermediate,
+		index: usize,
+		who: Self::Member,
+		last: Self::Member,
+		_lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
+	) -> Result<(), VerifiableError> {
+		remove_from_set(inter, index, who, last)
 	}
 	fn finish_members(inter: Self::Intermediate) -> Self::Members {
 		inter
@@ -114,18 +236,16 @@ impl GenerateVerifiable for Simple {
 	}
 
 	fn member_from_secret(secret: &Self::Secret) -> Self::Member {
-		let secret = MiniSecretKey::from_bytes(&secret[..]).unwrap();
-		let pair = secret.expand_to_keypair(ExpansionMode::Ed25519);
-		pair.public.to_bytes()
+		keypair(secret).public.to_bytes()
 	}
 
 	fn open(
 		member: &Self::Member,
 		members: impl Iterator<Item = Self::Member>,
-	) -> Result<Self::Commitment, ()> {
+	) -> Result<Self::Commitment, VerifiableError> {
 		let set = members.collect::<Vec<_>>();
 		if !set.contains(member) {
-			return Err(());
+			return Err(VerifiableError::NotMember);
 		}
 		Ok((member.clone(), set))
 	}
@@ -135,18 +255,24 @@ impl GenerateVerifiable for Simple {
 		secret: &Self::Secret,
 		context: &[u8],
 		message: &[u8],
-	) -> Result<(Self::Proof, Alias), ()> {
+	) -> Result<(Self::Proof, Alias), VerifiableError> {
 		let public = Self::member_from_secret(&secret);
 		if member != public {
-			return Err(());
+			return Err(VerifiableError::SecretMismatch);
 		}
 
-		let secret = MiniSecretKey::from_bytes(&secret[..]).unwrap();
-		let pair = secret.expand_to_keypair(ExpansionMode::Ed25519);
-
-		let sig = (context, message)
-			.using_encoded(|b| pair.sign(signing_context(SIG_CON).bytes(b)).to_bytes());
-		Ok(((sig, public.clone()), public))
+		let pair = keypair(secret);
+
+		// The message is bound by the VRF proof, but does not affect the output.
+		let (inout, proof, _) = pair.vrf_sign_extra(
+			signing_context(VRF_CON).bytes(context),
+			signing_context(SIG_CON).bytes(message),
+		);
+		let alias = inout.make_bytes::<Alias>(ALIAS_CON);
+		Ok((
+			(public, inout.to_preout().to_bytes(), proof.to_bytes()),
+			alias,
+		))
 	}
 
 	fn validate(
@@ -154,17 +280,137 @@ impl GenerateVerifiable for Simple {
 		members: &Self::Members,
 		context: &[u8],
 		message: &[u8],
-	) -> Result<Alias, ()> {
-		if !members.contains(&proof.1) {
-			return Err(());
+	) -> Result<Alias, VerifiableError> {
+		let (member, output, vrf_proof) = proof;
+		if !members.contains(member) {
+			return Err(VerifiableError::NotMember);
 		}
-		let s = schnorrkel::Signature::from_bytes(&proof.0).unwrap();
-		let p = PublicKey::from_bytes(&proof.1).unwrap();
-		(context, message).using_encoded(|b| {
-			p.verify_simple(SIG_CON, b, &s)
-				.map(|_| proof.1.clone())
-				.map_err(|_| ())
-		})
+		let p = PublicKey::from_bytes(member).map_err(|_| VerifiableError::MalformedMember)?;
+		let output = VRFPreOut::from_bytes(output).map_err(|_| VerifiableError::MalformedProof)?;
+		let vrf_proof =
+			VRFProof::from_bytes(vrf_proof).map_err(|_| VerifiableError::MalformedProof)?;
+		let (inout, _) = p
+			.vrf_verify_extra(
+				signing_context(VRF_CON).bytes(context),
+				&output,
+				&vrf_proof,
+				signing_context(SIG_CON).bytes(message),
+			)
+			.map_err(|_| VerifiableError::InvalidProof)?;
+		Ok(inout.make_bytes::<Alias>(ALIAS_CON))
+	}
+
+	fn sign(secret: &Self::Secret, message: &[u8]) -> Result<Self::Signature, VerifiableError> {
+		Ok(keypair(secret)
+			.sign(signing_context(SIGN_CON).bytes(message))
+			.to_bytes())
+	}
+
+	fn verify_signature(
+		signature: &Self::Signature,
+		message: &[u8],
+		member: &Self::Member,
+	) -> bool {
+		let Ok(s) = schnorrkel::Signature::from_bytes(signature) else {
+			return false;
+		};
+		let Ok(p) = PublicKey::from_bytes(member) else {
+			return false;
+		};
+		p.verify_simple(SIGN_CON, message, &s).is_ok()
+	}
+}
+
+const LINK_CON: &[u8] = b"verifiable-link";
+const OWNERSHIP_CON: &[u8] = b"verifiable-ownership";
+
+/// The message of a `Simple` proof which is one half of a link with `other_context`.
+fn link_message(other_context: &[u8]) -> Vec<u8> {
+	(LINK_CON, other_context).encode()
+}
+
+impl LinkableAliases for Simple {
+	/// A proof in each of the two contexts, each with the other context as its message.
+	type LinkProof = (Self::Proof, Self::Proof);
+
+	fn prove_alias_link(
+		commitment: Self::Commitment,
+		secret: &Self::Secret,
+		context_a: &[u8],
+		context_b: &[u8],
+	) -> Result<(Self::LinkProof, Alias, Alias), VerifiableError> {
+		let (proof_a, alias_a) = Self::create(
+			commitment.clone(),
+			secret,
+			context_a,
+			&link_message(context_b),
+		)?;
+		let (proof_b, alias_b) =
+			Self::create(commitment, secret, context_b, &link_message(context_a))?;
+		Ok(((proof_a, proof_b), alias_a, alias_b))
+	}
+
+	fn verify_alias_link(
+		(proof_a, proof_b): &Self::LinkProof,
+		members: &Self::Members,
+		context_a: &[u8],
+		alias_a: &Alias,
+		context_b: &[u8],
+		alias_b: &Alias,
+	) -> bool {
+		proof_a.0 == proof_b.0
+			&& Self::is_valid(
+				proof_a,
+				members,
+				context_a,
+				alias_a,
+				&link_message(context_b),
+			) && Self::is_valid(
+			proof_b,
+			members,
+			context_b,
+			alias_b,
+			&link_message(context_a),
+		)
+	}
+}
+
+impl AliasOwnership for Simple {
+	/// The VRF pre-output and the VRF proof, made without any message.
+	type OwnershipProof = ([u8; 32], [u8; 64]);
+
+	fn prove_alias_ownership(
+		secret: &Self::Secret,
+		context: &[u8],
+	) -> Result<(Self::OwnershipProof, Alias), VerifiableError> {
+		let (inout, proof, _) = keypair(secret).vrf_sign_extra(
+			signing_context(VRF_CON).bytes(context),
+			signing_context(OWNERSHIP_CON).bytes(&[]),
+		);
+		let alias = inout.make_bytes::<Alias>(ALIAS_CON);
+		Ok(((inout.to_preout().to_bytes(), proof.to_bytes()), alias))
+	}
+
+	fn verify_alias_ownership(
+		(output, vrf_proof): &Self::OwnershipProof,
+		member: &Self::Member,
+		context: &[u8],
+		alias: &Alias,
+	) -> bool {
+		let (Ok(p), Ok(output), Ok(vrf_proof)) = (
+			PublicKey::from_bytes(member),
+			VRFPreOut::from_bytes(output),
+			VRFProof::from_bytes(vrf_proof),
+		) else {
+			return false;
+		};
+		p.vrf_verify_extra(
+			signing_context(VRF_CON).bytes(context),
+			&output,
+			&vrf_proof,
+			signing_context(OWNERSHIP_CON).bytes(&[]),
+		)
+		.is_ok_and(|(inout, _)| &inout.make_bytes::<Alias>(ALIAS_CON) == alias)
 	}
 }
 
@@ -196,21 +442,252 @@ mod tests {
 			message.to_vec(),
 		)
 		.unwrap();
+		let alice_alias = *r.alias();
 		let (alias, msg) = r.verify(&members, &context).unwrap();
 		assert_eq!(&message[..], &msg[..]);
-		assert_eq!(alias, alice);
+		assert_eq!(alias, alice_alias);
+		assert_ne!(alias, alice);
 
 		let r = SimpleReceipt::create(&bob_sec, members.iter().cloned(), context, message.to_vec())
 			.unwrap();
+		let bob_alias = *r.alias();
 		let (alias, msg) = r.verify(&members, &context).unwrap();
 		assert_eq!(&message[..], &msg[..]);
-		assert_eq!(alias, bob);
+		assert_eq!(alias, bob_alias);
+		assert_ne!(alias, bob);
+		assert_ne!(alias, alice_alias);
 
-		assert!(SimpleReceipt::create(
-			&charlie_sec,
+		let r = SimpleReceipt::create(
+			&alice_sec,
 			members.iter().cloned(),
-			context,
-			message.to_vec()
+			b"Other context",
+			message.to_vec(),
+		)
+		.unwrap();
+		assert_ne!(*r.alias(), alice_alias);
+		assert!(r.is_valid(&members, b"Other context"));
+		assert!(!r.is_valid(&members, context));
+
+		assert_eq!(
+			SimpleReceipt::create(
+				&charlie_sec,
+				members.iter().cloned(),
+				context,
+				message.to_vec()
+			)
+			.err(),
+			Some(VerifiableError::NotMember)
+		);
+	}
+
+	#[test]
+	fn simple_remove_replace_matches_fresh_set() {
+		let members: Vec<_> = (0..5u8)
+			.map(|i| {
+				let secret = <Simple as GenerateVerifiable>::new_secret([i; 32]);
+				<Simple as GenerateVerifiable>::member_from_secret(&secret)
+			})
+			.collect();
+		let dave = <Simple as GenerateVerifiable>::member_from_secret(&[9u8; 32]);
+		let build = |set: &[[u8; 32]]| {
+			let mut inter = <Simple as GenerateVerifiable>::start_members();
+			for who in set {
+				<Simple as GenerateVerifiable>::push_member(&mut inter, *who, |_| Ok(())).unwrap();
+			}
+			inter
+		};
+
+		let mut inter = build(&members);
+		<Simple as GenerateVerifiable>::replace_member(&mut inter, 2, members[2], dave, |_| Ok(()))
+			.unwrap();
+		assert_eq!(
+			inter,
+			build(&[members[0], members[1], dave, members[3], members[4]])
+		);
+
+		<Simple as GenerateVerifiable>::remove_member(
+			&mut inter,
+			1,
+			members[1],
+			members[4],
+			|_| Ok(()),
+		)
+		.unwrap();
+		assert_eq!(inter, build(&[members[0], members[4], dave, members[3]]));
+
+		<Simple as GenerateVerifiable>::remove_member(
+			&mut inter,
+			3,
+			members[3],
+			members[3],
+			|_| Ok(()),
+		)
+		.unwrap();
+		assert_eq!(inter, build(&[members[0], members[4], dave]));
+
+		assert_eq!(
+			<Simple as GenerateVerifiable>::replace_member(
+				&mut inter,
+				0,
+				members[1],
+				dave,
+				|_| Ok(())
+			),
+			Err(VerifiableError::NotMember)
+		);
+		assert_eq!(
+			<Simple as GenerateVerifiable>::remove_member(&mut inter, 3, dave, dave, |_| Ok(())),
+			Err(VerifiableError::MemberIndexOutOfRange)
+		);
+	}
+
+	#[test]
+	fn simple_capacity() {
+		let mut inter = <Simple as GenerateVerifiable>::start_members();
+		let capacity = <Simple as GenerateVerifiable>::capacity();
+		for i in 0..capacity {
+			assert_eq!(<Simple as GenerateVerifiable>::member_count(&inter), i);
+			assert!(!<Simple as GenerateVerifiable>::is_full(&inter));
+			let who = (i as u32).using_encoded(|b| {
+				let mut who = [0u8; 32];
+				who[..4].copy_from_slice(b);
+				who
+			});
+			<Simple as GenerateVerifiable>::push_member(&mut inter, who, |_| Ok(())).unwrap();
+		}
+		assert!(<Simple as GenerateVerifiable>::is_full(&inter));
+		assert_eq!(
+			<Simple as GenerateVerifiable>::push_member(&mut inter, [0xff; 32], |_| Ok(())),
+			Err(VerifiableError::RingFull)
+		);
+		assert_eq!(
+			<Simple as GenerateVerifiable>::member_count(&inter),
+			capacity
+		);
+	}
+
+	#[test]
+	fn simple_plain_signature() {
+		let msg = b"asd";
+		let secret = <Simple as GenerateVerifiable>::new_secret([0; 32]);
+		let public = <Simple as GenerateVerifiable>::member_from_secret(&secret);
+		let signature = <Simple as GenerateVerifiable>::sign(&secret, msg).unwrap();
+		let res = <Simple as GenerateVerifiable>::verify_signature(&signature, msg, &public);
+		assert!(res);
+
+		let other = <Simple as GenerateVerifiable>::member_from_secret(&[1; 32]);
+		assert!(!<Simple as GenerateVerifiable>::verify_signature(
+			&signature, b"asf", &public
+		));
+		assert!(!<Simple as GenerateVerifiable>::verify_signature(
+			&signature, msg, &other
+		));
+		assert!(!<Simple as GenerateVerifiable>::verify_signature(
+			&[0; 64], msg, &public
+		));
+
+		// The VRF proofs of `create` bind the message too, but are not signatures of it.
+		let commitment =
+			<Simple as GenerateVerifiable>::open(&public, core::iter::once(public)).unwrap();
+		let ((_, _, vrf_proof), _) =
+			<Simple as GenerateVerifiable>::create(commitment, &secret, b"", msg).unwrap();
+		assert!(!<Simple as GenerateVerifiable>::verify_signature(
+			&vrf_proof, msg, &public
+		));
+	}
+
+	#[test]
+	fn trivial_conformance() {
+		crate::testing::conformance::<Trivial>(|range| Ok(range.map(|_| ()).collect()));
+	}
+
+	#[test]
+	fn trivial_binds_context_and_message() {
+		let alice = <Trivial as GenerateVerifiable>::new_secret([0u8; 32]);
+		let bob = <Trivial as GenerateVerifiable>::new_secret([1u8; 32]);
+		let mut inter = <Trivial as GenerateVerifiable>::start_members();
+		<Trivial as GenerateVerifiable>::push_member(&mut inter, alice, |_| Ok(())).unwrap();
+		<Trivial as GenerateVerifiable>::push_member(&mut inter, bob, |_| Ok(())).unwrap();
+		let members = <Trivial as GenerateVerifiable>::finish_members(inter);
+
+		let commitment = <Trivial as GenerateVerifiable>::open(&alice, members.iter().cloned());
+		let (proof, alias) =
+			<Trivial as GenerateVerifiable>::create(commitment.unwrap(), &alice, b"ctx", b"msg")
+				.unwrap();
+		assert_ne!(alias, alice);
+		assert_eq!(
+			<Trivial as GenerateVerifiable>::validate(&proof, &members, b"ctx", b"msg"),
+			Ok(alias)
+		);
+		assert_eq!(
+			<Trivial as GenerateVerifiable>::validate(&proof, &members, b"other", b"msg"),
+			Err(VerifiableError::InvalidProof)
+		);
+		assert_eq!(
+			<Trivial as GenerateVerifiable>::validate(&proof, &members, b"ctx", b"other"),
+			Err(VerifiableError::InvalidProof)
+		);
+
+		let commitment = <Trivial as GenerateVerifiable>::open(&alice, members.iter().cloned());
+		let (_, other_alias) =
+			<Trivial as GenerateVerifiable>::create(commitment.unwrap(), &alice, b"other", b"msg")
+				.unwrap();
+		assert_ne!(alias, other_alias);
+	}
+
+	#[test]
+	fn simple_conformance() {
+		crate::testing::conformance::<Simple>(|range| Ok(range.map(|_| ()).collect()));
+	}
+
+	#[test]
+	fn simple_alias_links() {
+		let fixture =
+			crate::testing::Fixture::<Simple>::new(|range| Ok(range.map(|_| ()).collect()));
+		crate::testing::check_alias_links(&fixture);
+	}
+
+	#[test]
+	fn simple_alias_ownership() {
+		let fixture =
+			crate::testing::Fixture::<Simple>::new(|range| Ok(range.map(|_| ()).collect()));
+		crate::testing::check_alias_ownership(&fixture);
+	}
+
+	#[test]
+	fn simple_validate_malformed_proof() {
+		let alice_sec = <Simple as GenerateVerifiable>::new_secret([0u8; 32]);
+		let alice = <Simple as GenerateVerifiable>::member_from_secret(&alice_sec);
+		let mut inter = <Simple as GenerateVerifiable>::start_members();
+		<Simple as GenerateVerifiable>::push_member(&mut inter, alice, |_| Ok(())).unwrap();
+		// Not a valid point, but still a member.
+		<Simple as GenerateVerifiable>::push_member(&mut inter, [0xff; 32], |_| Ok(())).unwrap();
+		let members = <Simple as GenerateVerifiable>::finish_members(inter);
+
+		// VRF proofs must hold canonical scalars.
+		assert_eq!(
+			<Simple as GenerateVerifiable>::validate(
+				&(alice, [0; 32], [0xff; 64]),
+				&members,
+				b"",
+				b""
+			),
+			Err(VerifiableError::MalformedProof)
+		);
+		assert_eq!(
+			<Simple as GenerateVerifiable>::validate(
+				&([0xff; 32], [0; 32], [0; 64]),
+				&members,
+				b"",
+				b""
+			),
+			Err(VerifiableError::MalformedMember)
+		);
+		assert!(<Simple as GenerateVerifiable>::validate(
+			&(alice, [0; 32], [0; 64]),
+			&members,
+			b"",
+			b""
 		)
 		.is_err());
 	}
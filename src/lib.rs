@@ -6,11 +6,18 @@ extern crate core;
 use alloc::vec::Vec;
 
 use core::fmt::Debug;
+use core::ops::Range;
 use parity_scale_codec::{Decode, Encode, FullCodec, MaxEncodedLen};
 use scale_info::*;
 
 pub mod demo_impls;
+pub mod nullifier;
+pub mod rate_limit;
 pub mod ring_vrf_impl;
+#[cfg(feature = "setup")]
+pub mod setup;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
 // Fixed types:
 
@@ -25,6 +32,47 @@ pub type Alias = [u8; 32];
 /// Entropy supplied for the creation of a secret key.
 pub type Entropy = [u8; 32];
 
+/// Reason why an operation of `GenerateVerifiable` (or `Receipt`) failed.
+///
+/// This is `Encode`/`Decode`/`TypeInfo` so that it may be passed across a runtime boundary.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, Debug, TypeInfo, MaxEncodedLen)]
+pub enum VerifiableError {
+	/// The member is not part of the given set of members.
+	NotMember,
+	/// The set of members cannot take any more members.
+	RingFull,
+	/// A member index is beyond the maximum size of the set of members.
+	MemberIndexOutOfRange,
+	/// The static data needed to build the set of members could not be looked up.
+	LookupFailed,
+	/// The commitment was opened for a different member than the one owning the secret.
+	SecretMismatch,
+	/// The proof bytes could not be decoded.
+	MalformedProof,
+	/// The member (public key) bytes could not be decoded.
+	MalformedMember,
+	/// The proof or signature is well-formed but does not verify.
+	InvalidProof,
+	/// A value could not be serialized or deserialized.
+	Serialization,
+	/// The value was made for a different set of members.
+	MembersMismatch,
+	/// The alias was already used in the context.
+	AlreadyUsed,
+	/// The action counter is not below the rate limit.
+	RateLimitExceeded,
+	/// The value was made for a different epoch.
+	WrongEpoch,
+	/// The value is for a ring domain of another size.
+	DomainMismatch,
+	/// The parameters needed by the operation have not been installed (nor embedded).
+	ParamsUnavailable,
+	/// The parameters are malformed or not for the expected domain size.
+	InvalidParams,
+	/// The operation is not supported by this implementation (or in this environment).
+	Unsupported,
+}
+
 // The trait. This (alone) must be implemented in its entirely by the Ring-VRF.
 
 /// Trait allowing cryptographic proof of membership of a set with known members under multiple
@@ -75,13 +123,78 @@ pub trait GenerateVerifiable {
 	/// Begin building a `Members` value.
 	fn start_members() -> Self::Intermediate;
 
+	/// The number of members introduced into the intermediate value so far.
+	fn member_count(intermediate: &Self::Intermediate) -> usize;
+
+	/// The maximum number of members which a `Members` value can hold.
+	fn capacity() -> usize;
+
+	/// Whether no more members can be introduced into the intermediate value.
+	fn is_full(intermediate: &Self::Intermediate) -> bool {
+		Self::member_count(intermediate) >= Self::capacity()
+	}
+
 	/// Introduce a new `Member` into the intermediate value used to build a new `Members` value.
+	///
+	/// Fails with `VerifiableError::RingFull` if the intermediate value already holds `capacity`
+	/// members.
 	fn push_member(
 		intermediate: &mut Self::Intermediate,
 		who: Self::Member,
 		lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
-	) -> Result<(), ()>;
-	// push_members
+	) -> Result<(), VerifiableError>;
+
+	/// Introduce a number of new `Member`s into the intermediate value used to build a new
+	/// `Members` value.
+	///
+	/// Unlike `push_member`, `lookup` is asked for the static chunks of a whole range of
+	/// positions at once. The default implementation just calls `push_member` for each member in
+	/// turn, so on error some of the `members` may already have been introduced.
+	fn push_members(
+		intermediate: &mut Self::Intermediate,
+		members: impl Iterator<Item = Self::Member>,
+		lookup: impl Fn(Range<usize>) -> Result<Vec<Self::StaticChunk>, ()>,
+	) -> Result<(), VerifiableError> {
+		for who in members {
+			Self::push_member(intermediate, who, |i| {
+				lookup(i..i + 1)?.into_iter().next().ok_or(())
+			})?;
+		}
+		Ok(())
+	}
+
+	/// Replace the member `who`, found at position `index` of the intermediate value, with `new`.
+	///
+	/// Implementations which cannot inspect their members (such as a ring commitment) trust that
+	/// `who` really is the member at `index`.
+	fn replace_member(
+		_intermediate: &mut Self::Intermediate,
+		_index: usize,
+		_who: Self::Member,
+		_new: Self::Member,
+		_lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
+	) -> Result<(), VerifiableError> {
+		Err(VerifiableError::Unsupported)
+	}
+
+	/// Remove the member `who`, found at position `index` of the intermediate value.
+	///
+	/// Like `Vec::swap_remove`, the last member `last` is moved into the vacated position, so the
+	/// result is equal to a freshly built value with `last` pushed at `index` instead of `who`.
+	/// When `index` is the last position, `last` must be equal to `who`.
+	///
+	/// Implementations which cannot inspect their members (such as a ring commitment) trust that
+	/// `who` and `last` really are the members at `index` and at the last position.
+	fn remove_member(
+		_intermediate: &mut Self::Intermediate,
+		_index: usize,
+		_who: Self::Member,
+		_last: Self::Member,
+		_lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
+	) -> Result<(), VerifiableError> {
+		Err(VerifiableError::Unsupported)
+	}
+
 	/// Consume the `intermediate` value to create a new `Members` value.
 	fn finish_members(inter: Self::Intermediate) -> Self::Members;
 
@@ -104,7 +217,7 @@ pub trait GenerateVerifiable {
 	fn open(
 		member: &Self::Member,
 		members_iter: impl Iterator<Item = Self::Member>,
-	) -> Result<Self::Commitment, ()>;
+	) -> Result<Self::Commitment, VerifiableError>;
 
 	/// Create a proof of membership with the `commitment` using the given `secret` of the member
 	/// of the `commitment`.
@@ -123,11 +236,11 @@ pub trait GenerateVerifiable {
 		secret: &Self::Secret,
 		context: &[u8],
 		message: &[u8],
-	) -> Result<(Self::Proof, Alias), ()>;
+	) -> Result<(Self::Proof, Alias), VerifiableError>;
 
 	/// Make a non-anonymous signature of `message` using `secret`.
-	fn sign(_secret: &Self::Secret, _message: &[u8]) -> Result<Self::Signature, ()> {
-		Err(())
+	fn sign(_secret: &Self::Secret, _message: &[u8]) -> Result<Self::Signature, VerifiableError> {
+		Err(VerifiableError::Unsupported)
 	}
 
 	/// Check whether `self` is a valid proof of membership in `members` in the given `context`;
@@ -142,7 +255,7 @@ pub trait GenerateVerifiable {
 	) -> bool {
 		match Self::validate(proof, members, context, message) {
 			Ok(a) => &a == alias,
-			Err(()) => false,
+			Err(_) => false,
 		}
 	}
 
@@ -152,8 +265,8 @@ pub trait GenerateVerifiable {
 		_members: &Self::Members,
 		_context: &[u8],
 		_message: &[u8],
-	) -> Result<Alias, ()> {
-		Err(())
+	) -> Result<Alias, VerifiableError> {
+		Err(VerifiableError::Unsupported)
 	}
 
 	fn verify_signature(
@@ -165,6 +278,58 @@ pub trait GenerateVerifiable {
 	}
 }
 
+/// Optional extension of `GenerateVerifiable` with which a member may voluntarily reveal that their
+/// aliases in two contexts belong to the same member, without revealing which member they are.
+///
+/// A thin-VRF (as used by `sign`) is verified against the signer's `Member` and so would reveal
+/// them; a link is therefore proven as a membership in `members`, like `create`, which is why it
+/// needs a `Commitment` and is verified against the `Members`.
+pub trait LinkableAliases: GenerateVerifiable {
+	/// A proof that two aliases belong to the same member.
+	type LinkProof: Clone + Eq + PartialEq + FullCodec + Debug + TypeInfo;
+
+	/// Prove that the member of `commitment` (owning `secret`) has both of the returned aliases,
+	/// the first in `context_a` and the second in `context_b`.
+	fn prove_alias_link(
+		commitment: Self::Commitment,
+		secret: &Self::Secret,
+		context_a: &[u8],
+		context_b: &[u8],
+	) -> Result<(Self::LinkProof, Alias, Alias), VerifiableError>;
+
+	/// Check whether `proof` shows that a single member of `members` has `alias_a` in `context_a`
+	/// and `alias_b` in `context_b`.
+	fn verify_alias_link(
+		proof: &Self::LinkProof,
+		members: &Self::Members,
+		context_a: &[u8],
+		alias_a: &Alias,
+		context_b: &[u8],
+		alias_b: &Alias,
+	) -> bool;
+}
+
+/// Optional extension of `GenerateVerifiable` with which a member may (non-anonymously) disclose
+/// that an alias of theirs is theirs, e.g. to an auditor, by tying it to their `Member`.
+pub trait AliasOwnership: GenerateVerifiable {
+	/// A proof that an alias in some context belongs to a particular member.
+	type OwnershipProof: Clone + Eq + PartialEq + FullCodec + Debug + TypeInfo;
+
+	/// Prove that the member owning `secret` has the returned alias in `context`.
+	fn prove_alias_ownership(
+		secret: &Self::Secret,
+		context: &[u8],
+	) -> Result<(Self::OwnershipProof, Alias), VerifiableError>;
+
+	/// Check whether `proof` shows that `member` has `alias` in `context`.
+	fn verify_alias_ownership(
+		proof: &Self::OwnershipProof,
+		member: &Self::Member,
+		context: &[u8],
+		alias: &Alias,
+	) -> bool;
+}
+
 // This is just a convenience struct to help manage some of the witness data. No need to look at it.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
 pub struct Receipt<Gen: GenerateVerifiable> {
@@ -179,7 +344,7 @@ impl<Gen: GenerateVerifiable> Receipt<Gen> {
 		members: impl Iterator<Item = Gen::Member>,
 		context: &[u8],
 		message: Vec<u8>,
-	) -> Result<Self, ()>
+	) -> Result<Self, VerifiableError>
 	where
 		Gen::Member: 'a,
 	{
@@ -203,7 +368,7 @@ impl<Gen: GenerateVerifiable> Receipt<Gen> {
 	pub fn verify(self, members: &Gen::Members, context: &[u8]) -> Result<(Alias, Vec<u8>), Self> {
 		match Gen::validate(&self.proof, members, context, &self.message) {
 			Ok(alias) => Ok((alias, self.message)),
-			Err(()) => {
+			Err(_) => {
 				if self.is_valid(members, context) {
 					Ok(self.into_parts())
 				} else {
@@ -212,6 +377,23 @@ impl<Gen: GenerateVerifiable> Receipt<Gen> {
 			}
 		}
 	}
+	/// Like `verify`, but also record the alias as used in `context` within `nullifiers`,
+	/// failing with `VerifiableError::AlreadyUsed` if it was used there before.
+	pub fn verify_fresh(
+		self,
+		members: &Gen::Members,
+		context: &[u8],
+		nullifiers: &mut impl nullifier::Nullifiers,
+	) -> Result<(Alias, Vec<u8>), VerifiableError> {
+		let alias = nullifier::validate_fresh::<Gen>(
+			nullifiers,
+			&self.proof,
+			members,
+			context,
+			&self.message,
+		)?;
+		Ok((alias, self.message))
+	}
 	pub fn is_valid(&self, members: &Gen::Members, context: &[u8]) -> bool {
 		Gen::is_valid(&self.proof, members, context, &self.alias, &self.message)
 	}
@@ -0,0 +1,219 @@
+//! One-time use of aliases: each member may act only once per context.
+//!
+//! Since the `Alias` of a member is deterministic within a context, remembering every
+//! `(context, Alias)` pair which has been used is enough to reject a member acting twice.
+//!
+//! The pairs are kept by an implementation of `Nullifiers`. On-chain, that is a storage map with
+//! one entry per pair, under its `key`; `NullifierSet` keeps them all in memory instead.
+
+use alloc::collections::BTreeSet;
+use blake2::{digest::consts::U32, Blake2b, Digest};
+
+use super::*;
+
+/// The `(context, Alias)` pairs which have already been used.
+pub trait Nullifiers {
+	/// Whether `alias` was already used in `context`.
+	fn contains(&self, context: &[u8], alias: &Alias) -> bool;
+
+	/// Record `alias` as used in `context`.
+	///
+	/// Fails with `VerifiableError::AlreadyUsed` (recording nothing) if it was already used
+	/// there.
+	fn insert(&mut self, context: &[u8], alias: Alias) -> Result<(), VerifiableError>;
+}
+
+/// The key under which to keep `alias` as used in `context` in a storage map: the BLAKE2b-256
+/// hash of the encoded pair.
+pub fn key(context: &[u8], alias: &Alias) -> [u8; 32] {
+	(context, alias).using_encoded(|pair| Blake2b::<U32>::digest(pair).into())
+}
+
+/// The `(context, Alias)` pairs which have already been used, all held in memory.
+///
+/// This is for off-chain and test use: although it is `Encode`/`Decode`, it is a single value
+/// which grows without bound, so storing it on-chain means rewriting all of it on every insert.
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct NullifierSet {
+	used: BTreeSet<(Vec<u8>, Alias)>,
+}
+
+impl NullifierSet {
+	/// An empty set.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Forget about all aliases used in `context`, e.g. once it is over.
+	pub fn clear_context(&mut self, context: &[u8]) {
+		self.used.retain(|(c, _)| c != context);
+	}
+
+	/// The number of `(context, Alias)` pairs used.
+	pub fn len(&self) -> usize {
+		self.used.len()
+	}
+
+	/// Whether no alias was used yet.
+	pub fn is_empty(&self) -> bool {
+		self.used.is_empty()
+	}
+}
+
+impl Nullifiers for NullifierSet {
+	fn contains(&self, context: &[u8], alias: &Alias) -> bool {
+		self.used.contains(&(context.to_vec(), *alias))
+	}
+
+	fn insert(&mut self, context: &[u8], alias: Alias) -> Result<(), VerifiableError> {
+		if self.used.insert((context.to_vec(), alias)) {
+			Ok(())
+		} else {
+			Err(VerifiableError::AlreadyUsed)
+		}
+	}
+}
+
+/// Like `GenerateVerifiable::validate`, but also record the alias as used in `context` within
+/// `nullifiers`, failing with `VerifiableError::AlreadyUsed` if it was used there before.
+///
+/// Nothing is recorded if the proof is invalid.
+pub fn validate_fresh<Gen: GenerateVerifiable>(
+	nullifiers: &mut impl Nullifiers,
+	proof: &Gen::Proof,
+	members: &Gen::Members,
+	context: &[u8],
+	message: &[u8],
+) -> Result<Alias, VerifiableError> {
+	let alias = Gen::validate(proof, members, context, message)?;
+	nullifiers.insert(context, alias)?;
+	Ok(alias)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::demo_impls::Trivial;
+
+	#[test]
+	fn validate_fresh_works() {
+		let alice = Trivial::new_secret([0u8; 32]);
+		let bob = Trivial::new_secret([1u8; 32]);
+		let mut inter = Trivial::start_members();
+		Trivial::push_member(&mut inter, alice, |_| Ok(())).unwrap();
+		Trivial::push_member(&mut inter, bob, |_| Ok(())).unwrap();
+		let members = Trivial::finish_members(inter);
+		let prove = |secret, context: &[u8], message: &[u8]| {
+			let commitment = Trivial::open(secret, members.iter().cloned()).unwrap();
+			Trivial::create(commitment, secret, context, message).unwrap()
+		};
+
+		let mut nullifiers = NullifierSet::new();
+		let (proof, alias) = prove(&alice, b"vote", b"yes");
+		assert_eq!(
+			validate_fresh::<Trivial>(&mut nullifiers, &proof, &members, b"vote", b"yes"),
+			Ok(alias)
+		);
+		assert!(nullifiers.contains(b"vote", &alias));
+
+		// Another message in the same context is still the same alias.
+		let (proof, _) = prove(&alice, b"vote", b"no");
+		assert_eq!(
+			validate_fresh::<Trivial>(&mut nullifiers, &proof, &members, b"vote", b"no"),
+			Err(VerifiableError::AlreadyUsed)
+		);
+
+		// Invalid proofs are not recorded.
+		let (proof, bob_alias) = prove(&bob, b"vote", b"yes");
+		assert!(
+			validate_fresh::<Trivial>(&mut nullifiers, &proof, &members, b"vote", b"no").is_err()
+		);
+		assert!(!nullifiers.contains(b"vote", &bob_alias));
+		assert_eq!(
+			validate_fresh::<Trivial>(&mut nullifiers, &proof, &members, b"vote", b"yes"),
+			Ok(bob_alias)
+		);
+
+		// Other contexts are unaffected.
+		let (proof, other_alias) = prove(&alice, b"airdrop", b"claim");
+		assert_eq!(
+			validate_fresh::<Trivial>(&mut nullifiers, &proof, &members, b"airdrop", b"claim"),
+			Ok(other_alias)
+		);
+		assert_eq!(nullifiers.len(), 3);
+
+		let decoded = NullifierSet::decode(&mut &nullifiers.encode()[..]).unwrap();
+		assert_eq!(decoded, nullifiers);
+
+		nullifiers.clear_context(b"vote");
+		assert_eq!(nullifiers.len(), 1);
+		assert!(nullifiers.contains(b"airdrop", &other_alias));
+	}
+
+	/// Nullifiers kept like in a storage map, with one entry per pair.
+	#[derive(Default)]
+	struct KeyedNullifiers(BTreeSet<[u8; 32]>);
+
+	impl Nullifiers for KeyedNullifiers {
+		fn contains(&self, context: &[u8], alias: &Alias) -> bool {
+			self.0.contains(&key(context, alias))
+		}
+
+		fn insert(&mut self, context: &[u8], alias: Alias) -> Result<(), VerifiableError> {
+			if self.0.insert(key(context, &alias)) {
+				Ok(())
+			} else {
+				Err(VerifiableError::AlreadyUsed)
+			}
+		}
+	}
+
+	#[test]
+	fn keyed_nullifiers_work() {
+		let alice = Trivial::new_secret([0u8; 32]);
+		let mut inter = Trivial::start_members();
+		Trivial::push_member(&mut inter, alice, |_| Ok(())).unwrap();
+		let members = Trivial::finish_members(inter);
+		let commitment = Trivial::open(&alice, members.iter().cloned()).unwrap();
+		let (proof, alias) = Trivial::create(commitment, &alice, b"vote", b"yes").unwrap();
+
+		let mut nullifiers = KeyedNullifiers::default();
+		assert_eq!(
+			validate_fresh::<Trivial>(&mut nullifiers, &proof, &members, b"vote", b"yes"),
+			Ok(alias)
+		);
+		assert_eq!(
+			validate_fresh::<Trivial>(&mut nullifiers, &proof, &members, b"vote", b"yes"),
+			Err(VerifiableError::AlreadyUsed)
+		);
+		assert!(nullifiers.contains(b"vote", &alias));
+		assert!(!nullifiers.contains(b"vote2", &alias));
+
+		// Keys do not collide across the boundary between context and alias.
+		assert_ne!(key(b"ab", &[0; 32]), key(b"a", &[0; 32]));
+		assert_ne!(key(b"vote", &alias), key(b"vote", &[0; 32]));
+	}
+
+	#[test]
+	fn receipt_verify_fresh_works() {
+		let alice = Trivial::new_secret([0u8; 32]);
+		let mut inter = Trivial::start_members();
+		Trivial::push_member(&mut inter, alice, |_| Ok(())).unwrap();
+		let members = Trivial::finish_members(inter);
+
+		let mut nullifiers = NullifierSet::new();
+		let receipt = || {
+			Receipt::<Trivial>::create(&alice, members.iter().cloned(), b"faucet", b"hi".to_vec())
+				.unwrap()
+		};
+		let (alias, message) = receipt()
+			.verify_fresh(&members, b"faucet", &mut nullifiers)
+			.unwrap();
+		assert_eq!(message, b"hi".to_vec());
+		assert!(nullifiers.contains(b"faucet", &alias));
+		assert_eq!(
+			receipt().verify_fresh(&members, b"faucet", &mut nullifiers),
+			Err(VerifiableError::AlreadyUsed)
+		);
+	}
+}
@@ -0,0 +1,236 @@
+//! Rate-limited proofs: each member may act at most `limit` times per epoch.
+//!
+//! A member's actions within an epoch are numbered by a counter below `limit`, and each proof is
+//! made in a context derived from `(context, epoch, counter)`. Every member thus has exactly
+//! `limit` aliases per epoch, one per counter value; recording the aliases in `Nullifiers`
+//! rejects any further action within the epoch.
+//!
+//! The counter is not hidden: it is part of the receipt and checked against `limit` in the clear,
+//! not proven to be in range. With an anonymous implementation such as
+//! `BandersnatchVrfVerifiable` the aliases are linked neither to their member nor to each other,
+//! within an epoch or across epochs, but the counters tell how active the members are: the
+//! number of receipts with counter `k` in an epoch is the number of members who acted more than
+//! `k` times in it. Leaving the counter out of the receipt would not help, as anyone could find
+//! it again by validating the proof in each of the `limit` slot contexts; hiding it needs a range
+//! proof within the ring proof, which `BandersnatchVrfVerifiable` does not have.
+
+use super::*;
+use nullifier::Nullifiers;
+
+/// A `Receipt` for the `counter`th action of a member within `epoch`.
+///
+/// The counter is public, see the module docs.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug, TypeInfo)]
+pub struct RateLimitedReceipt<Gen: GenerateVerifiable> {
+	epoch: u64,
+	counter: u32,
+	receipt: Receipt<Gen>,
+}
+
+impl<Gen: GenerateVerifiable> RateLimitedReceipt<Gen> {
+	/// The context in which the proof of the `counter`th action within `epoch` is made.
+	pub fn slot_context(context: &[u8], epoch: u64, counter: u32) -> Vec<u8> {
+		(b"RateLimited", context, epoch, counter).encode()
+	}
+
+	/// Create a receipt for the `counter`th action within `epoch`, which must be below `limit`.
+	pub fn create(
+		secret: &Gen::Secret,
+		members: impl Iterator<Item = Gen::Member>,
+		context: &[u8],
+		epoch: u64,
+		counter: u32,
+		limit: u32,
+		message: Vec<u8>,
+	) -> Result<Self, VerifiableError> {
+		if counter >= limit {
+			return Err(VerifiableError::RateLimitExceeded);
+		}
+		let slot_context = Self::slot_context(context, epoch, counter);
+		let receipt = Receipt::create(secret, members, &slot_context, message)?;
+		Ok(Self {
+			epoch,
+			counter,
+			receipt,
+		})
+	}
+
+	pub fn epoch(&self) -> u64 {
+		self.epoch
+	}
+	pub fn counter(&self) -> u32 {
+		self.counter
+	}
+	pub fn alias(&self) -> &Alias {
+		self.receipt.alias()
+	}
+	pub fn message(&self) -> &[u8] {
+		self.receipt.message()
+	}
+
+	/// Verify the receipt as an action within `epoch`, limited to `limit` actions per member, and
+	/// record its alias within `nullifiers`.
+	///
+	/// Returns the alias of the member for this action and the message.
+	pub fn verify(
+		self,
+		members: &Gen::Members,
+		context: &[u8],
+		epoch: u64,
+		limit: u32,
+		nullifiers: &mut impl Nullifiers,
+	) -> Result<(Alias, Vec<u8>), VerifiableError> {
+		if self.epoch != epoch {
+			return Err(VerifiableError::WrongEpoch);
+		}
+		if self.counter >= limit {
+			return Err(VerifiableError::RateLimitExceeded);
+		}
+		let slot_context = Self::slot_context(context, self.epoch, self.counter);
+		self.receipt
+			.verify_fresh(members, &slot_context, nullifiers)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::demo_impls::Simple;
+	use nullifier::NullifierSet;
+
+	type SimpleRateLimited = RateLimitedReceipt<Simple>;
+
+	#[test]
+	fn rate_limit_works() {
+		let alice = Simple::new_secret([0u8; 32]);
+		let bob = Simple::new_secret([1u8; 32]);
+		let mut inter = Simple::start_members();
+		Simple::push_member(&mut inter, Simple::member_from_secret(&alice), |_| Ok(())).unwrap();
+		Simple::push_member(&mut inter, Simple::member_from_secret(&bob), |_| Ok(())).unwrap();
+		let members = Simple::finish_members(inter);
+		let create = |secret, epoch, counter, limit| {
+			SimpleRateLimited::create(
+				secret,
+				members.iter().cloned(),
+				b"forum",
+				epoch,
+				counter,
+				limit,
+				b"post".to_vec(),
+			)
+		};
+
+		let mut nullifiers = NullifierSet::new();
+		let mut aliases = Vec::new();
+		for counter in 0..2 {
+			let receipt = create(&alice, 7, counter, 2).unwrap();
+			let (alias, _) = receipt
+				.verify(&members, b"forum", 7, 2, &mut nullifiers)
+				.unwrap();
+			aliases.push(alias);
+		}
+		assert_ne!(aliases[0], aliases[1]);
+
+		// The (limit + 1)th action can neither be created nor verified.
+		assert_eq!(
+			create(&alice, 7, 2, 2).err(),
+			Some(VerifiableError::RateLimitExceeded)
+		);
+		let receipt = create(&alice, 7, 2, 3).unwrap();
+		assert_eq!(
+			receipt.verify(&members, b"forum", 7, 2, &mut nullifiers),
+			Err(VerifiableError::RateLimitExceeded)
+		);
+
+		// Slots cannot be reused.
+		let receipt = create(&alice, 7, 1, 2).unwrap();
+		assert_eq!(
+			receipt.verify(&members, b"forum", 7, 2, &mut nullifiers),
+			Err(VerifiableError::AlreadyUsed)
+		);
+
+		// Other members have their own slots.
+		let receipt = create(&bob, 7, 0, 2).unwrap();
+		assert!(receipt
+			.verify(&members, b"forum", 7, 2, &mut nullifiers)
+			.is_ok());
+
+		// Receipts are only valid in their epoch, and aliases differ between epochs.
+		let receipt = create(&alice, 8, 0, 2).unwrap();
+		assert_eq!(
+			receipt
+				.clone()
+				.verify(&members, b"forum", 7, 2, &mut nullifiers),
+			Err(VerifiableError::WrongEpoch)
+		);
+		let (alias, message) = receipt
+			.verify(&members, b"forum", 8, 2, &mut nullifiers)
+			.unwrap();
+		assert_eq!(message, b"post".to_vec());
+		assert!(!aliases.contains(&alias));
+	}
+
+	#[test]
+	fn bandersnatch_rate_limit_works() {
+		use crate::ring_vrf_impl::{BandersnatchVrfVerifiable9, LagrangeSrsProvider, RingParams};
+
+		let _ = RingParams::from_bytes_unchecked(include_bytes!("ring-data/zcash-9.pk"))
+			.unwrap()
+			.install();
+		let srs = LagrangeSrsProvider::new(&include_bytes!("ring-data/zcash-9.vk")[..]).unwrap();
+		let secrets: Vec<_> = (0..4u8)
+			.map(|i| BandersnatchVrfVerifiable9::new_secret([i; 32]))
+			.collect();
+		let members: Vec<_> = secrets
+			.iter()
+			.map(BandersnatchVrfVerifiable9::member_from_secret)
+			.collect();
+		let mut inter = BandersnatchVrfVerifiable9::start_members();
+		BandersnatchVrfVerifiable9::push_members(
+			&mut inter,
+			members.iter().cloned(),
+			srs.lookup_range(),
+		)
+		.unwrap();
+		let ring = BandersnatchVrfVerifiable9::finish_members(inter);
+		let create = |i: usize, epoch, counter| {
+			RateLimitedReceipt::<BandersnatchVrfVerifiable9>::create(
+				&secrets[i],
+				members.iter().cloned(),
+				b"forum",
+				epoch,
+				counter,
+				2,
+				b"post".to_vec(),
+			)
+			.unwrap()
+		};
+
+		let mut nullifiers = NullifierSet::new();
+		let mut aliases = Vec::new();
+		for counter in 0..2 {
+			let receipt = create(0, 7, counter);
+			// The counter is revealed, unlike the member.
+			assert_eq!(receipt.counter(), counter);
+			let (alias, _) = receipt
+				.verify(&ring, b"forum", 7, 2, &mut nullifiers)
+				.unwrap();
+			aliases.push(alias);
+		}
+		assert_ne!(aliases[0], aliases[1]);
+		assert_eq!(
+			create(0, 7, 1).verify(&ring, b"forum", 7, 2, &mut nullifiers),
+			Err(VerifiableError::AlreadyUsed)
+		);
+
+		// Other members have their own slots, and other epochs other aliases.
+		let (alias, _) = create(1, 7, 0)
+			.verify(&ring, b"forum", 7, 2, &mut nullifiers)
+			.unwrap();
+		assert!(!aliases.contains(&alias));
+		let (alias, _) = create(0, 8, 0)
+			.verify(&ring, b"forum", 8, 2, &mut nullifiers)
+			.unwrap();
+		assert!(!aliases.contains(&alias));
+	}
+}
@@ -1,6 +1,7 @@
 use alloc::vec;
 use core::ops::Range;
 
+use ark_ec::{AffineRepr, CurveGroup};
 use ark_scale::ArkScale;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use bandersnatch_vrfs::bls12_381;
@@ -16,54 +17,243 @@ use super::*;
 
 pub use bandersnatch_vrfs;
 
-type ThinVrfSignature = bandersnatch_vrfs::ThinVrfSignature<0>;
-type RingVrfSignature = bandersnatch_vrfs::RingVrfSignature<1>;
+type ThinVrfSignature<const N: usize> = bandersnatch_vrfs::ThinVrfSignature<N>;
+type RingVrfSignature<const N: usize> = bandersnatch_vrfs::RingVrfSignature<N>;
 
-#[cfg(feature = "small-ring")]
-mod domain_params {
-	use super::*;
-	pub const DOMAIN_SIZE: usize = 1 << 9;
-	pub(crate) const EMPTY_RING: RingCommitment = zcash_consts::EMPTY_RING_ZCASH_9;
-	pub(crate) const OFFCHAIN_PK: &[u8] = include_bytes!("ring-data/zcash-9.pk");
-}
+/// The log2 of the sizes of ring domain for which there are (Zcash) parameters: a small one,
+/// mostly useful for testing, and the default one.
+pub const LOG_DOMAINS: [u32; 2] = [9, 16];
 
-#[cfg(not(feature = "small-ring"))]
-mod domain_params {
-	use super::*;
-	pub const DOMAIN_SIZE: usize = 1 << 16;
-	pub(crate) const EMPTY_RING: RingCommitment = zcash_consts::EMPTY_RING_ZCASH_16;
-	pub(crate) const OFFCHAIN_PK: &[u8] = include_bytes!("ring-data/zcash-16.pk");
+/// The empty ring of the domain of size `2^log_domain`.
+const fn empty_ring(log_domain: u32) -> RingCommitment {
+	match log_domain {
+		9 => zcash_consts::EMPTY_RING_ZCASH_9,
+		16 => zcash_consts::EMPTY_RING_ZCASH_16,
+		_ => panic!("there are no parameters for this ring domain"),
+	}
 }
 
-pub use domain_params::*;
+/// The prover key compiled into the crate for the domain of size `2^log_domain`, if any.
+#[cfg(feature = "std")]
+#[allow(clippy::match_single_binding)] // Without any of the `embedded-params-*` features.
+fn embedded_pk(log_domain: u32) -> Option<&'static [u8]> {
+	match log_domain {
+		#[cfg(feature = "embedded-params-9")]
+		9 => Some(include_bytes!("ring-data/zcash-9.pk")),
+		// Not shipped with the sources: place it in `ring-data` before enabling the feature.
+		#[cfg(feature = "embedded-params-16")]
+		16 => Some(include_bytes!("ring-data/zcash-16.pk")),
+		_ => None,
+	}
+}
 
 const THIN_SIGNATURE_CONTEXT: &[u8] = b"VerifiableBandersnatchThinSignature";
+const OWNERSHIP_CONTEXT: &[u8] = b"VerifiableBandersnatchAliasOwnership";
 
 const VRF_INPUT_DOMAIN: &[u8] = b"VerifiableBandersnatchInput";
 const VRF_OUTPUT_DOMAIN: &[u8] = b"VerifiableBandersnatchInput";
 
 const THIN_SIGNATURE_SIZE: usize = 65;
 const RING_SIGNATURE_SIZE: usize = 788;
+/// A thin signature with one (32 byte) VRF pre-output.
+const OWNERSHIP_PROOF_SIZE: usize = THIN_SIGNATURE_SIZE + 32;
+/// A ring signature with one more (32 byte) VRF pre-output.
+const LINK_PROOF_SIZE: usize = RING_SIGNATURE_SIZE + 32;
 
+const LINK_MESSAGE: &[u8] = b"VerifiableBandersnatchAliasLink";
+
+/// The prover parameters of the domain of size `2^log_domain` used by `open` and `create`,
+/// once installed.
 #[cfg(feature = "std")]
-fn kzg() -> &'static KZG {
+fn params(log_domain: u32) -> Option<&'static std::sync::OnceLock<KZG>> {
 	use std::sync::OnceLock;
-	static CELL: OnceLock<KZG> = OnceLock::new();
-	CELL.get_or_init(|| {
-		let pk = StaticProverKey::deserialize_uncompressed_unchecked(OFFCHAIN_PK).unwrap();
-		KZG::kzg_setup(DOMAIN_SIZE, pk)
-	})
+	static PARAMS_9: OnceLock<KZG> = OnceLock::new();
+	static PARAMS_16: OnceLock<KZG> = OnceLock::new();
+	match log_domain {
+		9 => Some(&PARAMS_9),
+		16 => Some(&PARAMS_16),
+		_ => None,
+	}
+}
+
+/// The installed prover parameters of the domain of size `2^log_domain`, installing the embedded
+/// ones first if there are any.
+#[cfg(feature = "std")]
+fn kzg(log_domain: u32) -> Result<&'static KZG, VerifiableError> {
+	let cell = params(log_domain).ok_or(VerifiableError::DomainMismatch)?;
+	if cell.get().is_none() {
+		if let Some(pk) = embedded_pk(log_domain) {
+			let _ = RingParams::from_bytes_unchecked(pk)?.install();
+		}
+	}
+	cell.get().ok_or(VerifiableError::ParamsUnavailable)
+}
+
+/// The BLAKE2b-256 hash of the Zcash prover key of the domain of size `2^log_domain`, if pinned.
+///
+/// Only `zcash-9.pk` is pinned: it is the only key shipped with the sources.
+#[cfg(feature = "std")]
+fn zcash_pk_hash(log_domain: u32) -> Option<[u8; 32]> {
+	match log_domain {
+		9 => Some([
+			0x6b, 0xb4, 0xbb, 0x4e, 0xa9, 0xf1, 0x99, 0x86, 0xbb, 0x4d, 0xc4, 0xd0, 0x46, 0x53,
+			0xfb, 0xe3, 0xc4, 0x99, 0x1a, 0xc1, 0xf2, 0xf6, 0xcd, 0x48, 0x3a, 0x5d, 0x0b, 0x4e,
+			0x47, 0x3b, 0x7b, 0xbd,
+		]),
+		_ => None,
+	}
+}
+
+#[cfg(feature = "std")]
+fn blake2_256(data: &[u8]) -> [u8; 32] {
+	use blake2::{digest::consts::U32, Blake2b, Digest};
+	Blake2b::<U32>::digest(data).into()
+}
+
+/// The log2 of the size of the domain of the serialized prover key `pk`, from the number of
+/// powers of tau it starts with.
+#[cfg(feature = "std")]
+fn pk_log_domain(pk: &[u8]) -> Result<u32, VerifiableError> {
+	let powers = pk
+		.get(..8)
+		.map(|len| u64::from_le_bytes(len.try_into().expect("8 bytes; qed")));
+	LOG_DOMAINS
+		.into_iter()
+		.find(|log_domain| powers == Some(3 * (1 << log_domain) + 1))
+		.ok_or(VerifiableError::InvalidParams)
+}
+
+/// Check that `powers` are the powers of the tau of `kzg_vk` in G1, i.e. that they start with
+/// its generator and that `e(powers[i + 1], G2) == e(powers[i], tau G2)` for all `i`.
+///
+/// The pairings are checked all at once, for a random linear combination with the powers of a
+/// challenge derived from `transcript`, which must commit to `powers` and `kzg_vk`.
+#[cfg(feature = "std")]
+pub(crate) fn check_powers(
+	powers: &[bls12_381::G1Affine],
+	kzg_vk: &KzgVk,
+	transcript: &[u8],
+) -> Result<(), VerifiableError> {
+	use ark_ec::{pairing::Pairing, VariableBaseMSM};
+	use ark_ff::{Field, PrimeField};
+	use bls12_381::{Bls12_381, Fr, G1Projective};
+
+	if powers.len() < 2 || powers[0] != kzg_vk.g1 {
+		return Err(VerifiableError::InvalidParams);
+	}
+	let challenge = Fr::from_le_bytes_mod_order(&blake2_256(transcript));
+	let scalars: Vec<Fr> = core::iter::successors(Some(Fr::ONE), |r| Some(*r * challenge))
+		.take(powers.len() - 1)
+		.collect();
+	let lower = G1Projective::msm_unchecked(&powers[..powers.len() - 1], &scalars);
+	let upper = G1Projective::msm_unchecked(&powers[1..], &scalars);
+	if Bls12_381::pairing(upper.into_affine(), kzg_vk.g2)
+		!= Bls12_381::pairing(lower.into_affine(), kzg_vk.tau_in_g2)
+	{
+		return Err(VerifiableError::InvalidParams);
+	}
+	Ok(())
+}
+
+/// The parameters (SRS) needed to create ring proofs, i.e. to `open` and `create`, for rings of
+/// one of the `LOG_DOMAINS`.
+///
+/// These are only needed by provers, so they are loaded at runtime (from the `zcash-*.pk` file
+/// in `ring-data`, say) and installed once for the whole process. With the `embedded-params-*`
+/// features the parameters of a domain are compiled in and installed on first use instead.
+#[cfg(feature = "std")]
+pub struct RingParams {
+	log_domain: u32,
+	kzg: KZG,
+}
+
+#[cfg(feature = "std")]
+impl RingParams {
+	/// Decode and check the Zcash parameters from the serialized prover key, as in the
+	/// `zcash-*.pk` files.
+	///
+	/// The key is checked against `ZCASH_KZG_VK` as by `from_bytes_with_vk`. Only a key of
+	/// domain 9 must also match a pinned hash: `zcash-16.pk` is not shipped with the sources and
+	/// no hash is pinned for it, so a key of domain 16 is only checked against `ZCASH_KZG_VK`.
+	pub fn from_bytes(pk: &[u8]) -> Result<Self, VerifiableError> {
+		let log_domain = pk_log_domain(pk)?;
+		if zcash_pk_hash(log_domain).is_some_and(|hash| blake2_256(pk) != hash) {
+			return Err(VerifiableError::InvalidParams);
+		}
+		Self::from_bytes_with_vk(pk, &zcash_consts::ZCASH_KZG_VK)
+	}
+
+	/// Decode and check the parameters of a custom setup (see `setup`) from the serialized
+	/// prover key.
+	///
+	/// All of the points must be on the curve and in the subgroup, the key must end with
+	/// `kzg_vk`, and its powers of tau in G1 must be consistent with tau in G2 of `kzg_vk`.
+	pub fn from_bytes_with_vk(pk: &[u8], kzg_vk: &KzgVk) -> Result<Self, VerifiableError> {
+		// Before allocating for the powers.
+		pk_log_domain(pk)?;
+		let mut reader = pk;
+		let powers = Vec::<bls12_381::G1Affine>::deserialize_uncompressed(&mut reader)
+			.map_err(|_| VerifiableError::InvalidParams)?;
+		let mut expected_vk = Vec::with_capacity(kzg_vk.uncompressed_size());
+		kzg_vk
+			.serialize_uncompressed(&mut expected_vk)
+			.expect("serialization into a Vec cannot fail; qed");
+		if reader != &expected_vk[..] {
+			return Err(VerifiableError::InvalidParams);
+		}
+		check_powers(&powers, kzg_vk, pk)?;
+		// The points were all validated above.
+		Self::from_bytes_unchecked(pk)
+	}
+
+	/// Decode the parameters from the serialized prover key without checking them.
+	///
+	/// Only for keys from a trusted source, such as the ones compiled into the binary: checking
+	/// the key of domain 16 takes a while.
+	pub fn from_bytes_unchecked(pk: &[u8]) -> Result<Self, VerifiableError> {
+		let log_domain = pk_log_domain(pk)?;
+		let pk = StaticProverKey::deserialize_uncompressed_unchecked(pk)
+			.map_err(|_| VerifiableError::InvalidParams)?;
+		Ok(Self {
+			log_domain,
+			kzg: KZG::kzg_setup(1 << log_domain, pk),
+		})
+	}
+
+	/// Read, decode and check the Zcash parameters from the serialized prover key at `path`, as
+	/// with `from_bytes`.
+	pub fn load_prover_params(path: impl AsRef<std::path::Path>) -> Result<Self, VerifiableError> {
+		let pk = std::fs::read(path).map_err(|_| VerifiableError::ParamsUnavailable)?;
+		Self::from_bytes(&pk)
+	}
+
+	/// The log2 of the size of the ring domain of the parameters.
+	pub fn log_domain(&self) -> u32 {
+		self.log_domain
+	}
+
+	/// Use these parameters for all of the proofs created from now on for rings of their domain.
+	///
+	/// Parameters can only be installed once per domain; if some already are, `self` is given
+	/// back.
+	pub fn install(self) -> Result<(), Self> {
+		let log_domain = self.log_domain;
+		let cell = params(log_domain).expect("parameters are of a known domain; qed");
+		cell.set(self.kzg).map_err(|kzg| Self { log_domain, kzg })
+	}
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
 pub struct MembersSet {
 	pub ring: RingCommitment,
 	pub kzg_raw_vk: KzgVk,
+	/// The log2 of the size of the ring domain.
+	pub log_domain: u32,
 }
 
 ark_scale::impl_scale_via_ark!(MembersSet);
 
-const MEMBERS_SET_SIZE: usize = 4 * 48 + 2 * 96 + 32 + 2 * 4; // 4 bls G1 + 2 bls G2 + jubjub + 2 usize
+const MEMBERS_SET_SIZE: usize = 4 * 48 + 2 * 96 + 32 + 3 * 4; // 4 bls G1 + 2 bls G2 + jubjub + 2 usize + u32
 
 impl scale_info::TypeInfo for MembersSet {
 	type Identity = [u8; MEMBERS_SET_SIZE];
@@ -80,11 +270,21 @@ impl MaxEncodedLen for MembersSet {
 }
 
 #[derive(Clone, CanonicalDeserialize, CanonicalSerialize)]
-pub struct MembersCommitment(VerifierKey);
+pub struct MembersCommitment {
+	verifier_key: VerifierKey,
+	log_domain: u32,
+}
+
+impl MembersCommitment {
+	/// The log2 of the size of the ring domain.
+	pub fn log_domain(&self) -> u32 {
+		self.log_domain
+	}
+}
 
 ark_scale::impl_scale_via_ark!(MembersCommitment);
 
-const MEMBERS_COMMITMENT_SIZE: usize = 4 * 48 + 2 * 96; // 4 bls G1 + 2 bls G2
+const MEMBERS_COMMITMENT_SIZE: usize = 4 * 48 + 2 * 96 + 4; // 4 bls G1 + 2 bls G2 + u32
 
 impl scale_info::TypeInfo for MembersCommitment {
 	type Identity = [u8; MEMBERS_COMMITMENT_SIZE];
@@ -114,23 +314,580 @@ impl core::cmp::PartialEq for MembersCommitment {
 
 impl core::cmp::Eq for MembersCommitment {}
 
-pub struct BandersnatchVrfVerifiable;
+/// The size of a BLS12-381 G1 point serialized uncompressed.
+const G1_SIZE: usize = 96;
+/// The size of a `KzgVk` serialized uncompressed: 1 G1 point and 2 G2 points.
+const KZG_VK_SIZE: usize = G1_SIZE + 2 * 2 * G1_SIZE;
+
+/// Read access to the bytes of a serialized verifier key (as in the `zcash-*.vk` files), which
+/// need not be held all in one place.
+pub trait VerifierKeyBytes {
+	/// Fill `buf` with the bytes of the key starting at `offset`, failing if any are missing.
+	fn read(&self, offset: usize, buf: &mut [u8]) -> Result<(), ()>;
+}
+
+impl VerifierKeyBytes for &[u8] {
+	fn read(&self, offset: usize, buf: &mut [u8]) -> Result<(), ()> {
+		let bytes = self
+			.get(offset..)
+			.and_then(|b| b.get(..buf.len()))
+			.ok_or(())?;
+		buf.copy_from_slice(bytes);
+		Ok(())
+	}
+}
+
+impl VerifierKeyBytes for Vec<u8> {
+	fn read(&self, offset: usize, buf: &mut [u8]) -> Result<(), ()> {
+		(&self[..]).read(offset, buf)
+	}
+}
+
+/// A serialized verifier key split into pages of `page_size` bytes (the last one possibly
+/// shorter), e.g. one per storage item, which are fetched with `page` as they are needed.
+pub struct PagedBytes<F> {
+	page_size: usize,
+	page: F,
+}
+
+impl<F: Fn(usize) -> Result<Vec<u8>, ()>> PagedBytes<F> {
+	pub fn new(page_size: usize, page: F) -> Self {
+		Self { page_size, page }
+	}
+}
+
+impl<F: Fn(usize) -> Result<Vec<u8>, ()>> VerifierKeyBytes for PagedBytes<F> {
+	fn read(&self, mut offset: usize, buf: &mut [u8]) -> Result<(), ()> {
+		let mut filled = 0;
+		while filled < buf.len() {
+			let index = offset.checked_div(self.page_size).ok_or(())?;
+			let page = (self.page)(index)?;
+			let bytes = page.get(offset % self.page_size..).unwrap_or_default();
+			let len = bytes.len().min(buf.len() - filled);
+			if len == 0 {
+				return Err(());
+			}
+			buf[filled..filled + len].copy_from_slice(&bytes[..len]);
+			filled += len;
+			offset += len;
+		}
+		Ok(())
+	}
+}
+
+/// Serves the `StaticChunk`s (the Lagrange basis commitments) for `push_member` and friends, and
+/// the SRS for `start_members_from_params`, straight from a serialized verifier key, reading only
+/// the points asked for.
+///
+/// The key is laid out like the `zcash-*.vk` files: the number `n` of points as a little-endian
+/// `u64`, the `n` points in G1 and the `KzgVk`, all serialized uncompressed. The points are
+/// trusted to be valid, like the key itself.
+pub struct LagrangeSrsProvider<B> {
+	bytes: B,
+	log_domain: u32,
+}
+
+impl<B: VerifierKeyBytes> LagrangeSrsProvider<B> {
+	/// Serve the key held in `bytes`, once its header and length are checked to be those of a key
+	/// of one of the `LOG_DOMAINS`.
+	pub fn new(bytes: B) -> Result<Self, VerifiableError> {
+		let mut len = [0u8; 8];
+		bytes
+			.read(0, &mut len)
+			.map_err(|_| VerifiableError::InvalidParams)?;
+		let len = u64::from_le_bytes(len);
+		let log_domain = LOG_DOMAINS
+			.into_iter()
+			.find(|log_domain| len == 1 << log_domain)
+			.ok_or(VerifiableError::InvalidParams)?;
+		let provider = Self { bytes, log_domain };
+		// The key must be complete, up to the last byte of its `KzgVk`.
+		provider
+			.bytes
+			.read(provider.kzg_vk_offset() + KZG_VK_SIZE - 1, &mut [0u8])
+			.map_err(|_| VerifiableError::InvalidParams)?;
+		Ok(provider)
+	}
+
+	/// The log2 of the size of the ring domain of the key.
+	pub fn log_domain(&self) -> u32 {
+		self.log_domain
+	}
+
+	fn kzg_vk_offset(&self) -> usize {
+		8 + (G1_SIZE << self.log_domain)
+	}
+
+	/// The `KzgVk` at the end of the key, to pass to `start_members_from_params`.
+	pub fn kzg_vk(&self) -> Result<KzgVk, VerifiableError> {
+		let mut buf = [0u8; KZG_VK_SIZE];
+		self.bytes
+			.read(self.kzg_vk_offset(), &mut buf)
+			.map_err(|_| VerifiableError::InvalidParams)?;
+		KzgVk::deserialize_uncompressed(&buf[..]).map_err(|_| VerifiableError::InvalidParams)
+	}
+
+	/// The Lagrange basis commitments of the positions in `range`.
+	pub fn points(&self, range: Range<usize>) -> Result<Vec<bls12_381::G1Affine>, ()> {
+		if range.start > range.end || range.end > 1 << self.log_domain {
+			return Err(());
+		}
+		let mut buf = vec![0u8; range.len() * G1_SIZE];
+		self.bytes.read(8 + range.start * G1_SIZE, &mut buf)?;
+		buf.chunks_exact(G1_SIZE)
+			.map(|point| {
+				bls12_381::G1Affine::deserialize_uncompressed_unchecked(point).map_err(|_| ())
+			})
+			.collect()
+	}
+
+	/// The `lookup` for `push_member`, `replace_member` and `remove_member`.
+	pub fn lookup(&self) -> impl Fn(usize) -> Result<ArkScale<bls12_381::G1Affine>, ()> + '_ {
+		move |i| Ok(ArkScale(self.points(i..i.checked_add(1).ok_or(())?)?[0]))
+	}
+
+	/// The `lookup` for `push_members`.
+	pub fn lookup_range(
+		&self,
+	) -> impl Fn(Range<usize>) -> Result<Vec<ArkScale<bls12_381::G1Affine>>, ()> + '_ {
+		move |range| Ok(self.points(range)?.into_iter().map(ArkScale).collect())
+	}
+
+	/// The `srs` for `start_members_from_params`.
+	pub fn srs(&self) -> impl Fn(Range<usize>) -> Result<Vec<bls12_381::G1Affine>, ()> + '_ {
+		move |range| self.points(range)
+	}
+}
+
+/// Move the ring position whose Lagrange basis commitment is `base` from holding the point `from`
+/// to holding the point `to`.
+fn update_ring_slot(
+	ring: &mut RingCommitment,
+	base: bls12_381::G1Affine,
+	from: &PublicKey,
+	to: &PublicKey,
+) {
+	let (from, to) = (from.0, to.0);
+	ring.cx = (ring.cx.into_group() + base * (to.x - from.x)).into_affine();
+	ring.cy = (ring.cy.into_group() + base * (to.y - from.y)).into_affine();
+}
+
+fn vrf_input(context: &[u8]) -> VrfInput {
+	Message {
+		domain: VRF_INPUT_DOMAIN,
+		message: context,
+	}
+	.into_vrf_input()
+}
+
+fn ring_verify<const N: usize>(
+	ring_verifier: &bandersnatch_vrfs::ring::RingVerifier,
+	mut proof: &[u8],
+	contexts: [&[u8]; N],
+	message: &[u8],
+) -> Result<[Alias; N], VerifiableError> {
+	let ring_signature = RingVrfSignature::<N>::deserialize_compressed(&mut proof)
+		.map_err(|_| VerifiableError::MalformedProof)?;
+	if !proof.is_empty() {
+		return Err(VerifiableError::MalformedProof);
+	}
+
+	let ios = RingVerifier(ring_verifier)
+		.verify_ring_vrf(message, contexts.map(vrf_input), &ring_signature)
+		.map_err(|_| VerifiableError::InvalidProof)?;
+
+	Ok(core::array::from_fn(|i| {
+		ios[i].vrf_output_bytes(VRF_OUTPUT_DOMAIN)
+	}))
+}
+
+/// A `MembersCommitment` with the ring verifier (and its `piop_params`) already set up, so that
+/// repeated validation of proofs against the same ring only costs the check of each proof.
+///
+/// This works in `no_std`, so it may be kept around (e.g. in a per-block cache) for as long as the
+/// ring does not change.
+pub struct PreparedMembers {
+	members: MembersCommitment,
+	ring_verifier: bandersnatch_vrfs::ring::RingVerifier,
+}
+
+impl PreparedMembers {
+	/// Set up the verifier for `members`.
+	///
+	/// Fails with `VerifiableError::DomainMismatch` if `members` is not of one of the
+	/// `LOG_DOMAINS`.
+	pub fn new(members: MembersCommitment) -> Result<Self, VerifiableError> {
+		if !LOG_DOMAINS.contains(&members.log_domain) {
+			return Err(VerifiableError::DomainMismatch);
+		}
+		let ring_verifier = bandersnatch_vrfs::ring::make_ring_verifier(
+			members.verifier_key.clone(),
+			1 << members.log_domain,
+		);
+		Ok(Self {
+			members,
+			ring_verifier,
+		})
+	}
+
+	/// The ring for which the verifier was set up.
+	pub fn members(&self) -> &MembersCommitment {
+		&self.members
+	}
+
+	/// Same as `BandersnatchVrfVerifiable::validate` for the prepared ring.
+	pub fn validate(
+		&self,
+		proof: &[u8; RING_SIGNATURE_SIZE],
+		context: &[u8],
+		message: &[u8],
+	) -> Result<Alias, VerifiableError> {
+		let [alias] = ring_verify(&self.ring_verifier, proof, [context], message)?;
+		Ok(alias)
+	}
+
+	/// Same as `BandersnatchVrfVerifiable::validate_multi` for the prepared ring.
+	pub fn validate_multi<const N: usize>(
+		&self,
+		proof: &[u8],
+		contexts: [&[u8]; N],
+		message: &[u8],
+	) -> Result<[Alias; N], VerifiableError> {
+		ring_verify(&self.ring_verifier, proof, contexts, message)
+	}
+
+	/// Same as `BandersnatchVrfVerifiable::is_valid` for the prepared ring.
+	pub fn is_valid(
+		&self,
+		proof: &[u8; RING_SIGNATURE_SIZE],
+		context: &[u8],
+		alias: &Alias,
+		message: &[u8],
+	) -> bool {
+		match self.validate(proof, context, message) {
+			Ok(a) => &a == alias,
+			Err(_) => false,
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+fn ring_prove<const N: usize>(
+	ring_prover: &bandersnatch_vrfs::ring::RingProver,
+	secret: &SecretKey,
+	contexts: [&[u8]; N],
+	message: &[u8],
+) -> Result<(Vec<u8>, [Alias; N]), VerifiableError> {
+	let ios = contexts.map(|context| secret.vrf_inout(vrf_input(context)));
+
+	let signature: RingVrfSignature<N> = RingProver {
+		ring_prover,
+		secret,
+	}
+	.sign_ring_vrf(message, &ios);
+
+	let mut buf = Vec::with_capacity(signature.compressed_size());
+	signature
+		.serialize_compressed(&mut buf)
+		.map_err(|_| VerifiableError::Serialization)?;
+
+	let aliases = core::array::from_fn(|i| ios[i].vrf_output_bytes(VRF_OUTPUT_DOMAIN));
+
+	Ok((buf, aliases))
+}
+
+/// Like `ring_prove`, but for the single context of `GenerateVerifiable::create`.
+#[cfg(feature = "std")]
+fn ring_prove_one(
+	ring_prover: &bandersnatch_vrfs::ring::RingProver,
+	secret: &SecretKey,
+	context: &[u8],
+	message: &[u8],
+) -> Result<([u8; RING_SIGNATURE_SIZE], Alias), VerifiableError> {
+	let (proof, [alias]) = ring_prove(ring_prover, secret, [context], message)?;
+	let proof = proof
+		.try_into()
+		.map_err(|_| VerifiableError::Serialization)?;
+	Ok((proof, alias))
+}
+
+/// A ring prover set up once for a single member of a particular `MembersCommitment`, which can
+/// then create any number of proofs, for any `(context, message)` pairs, without redoing the
+/// setup done by `BandersnatchVrfVerifiable::create`.
+///
+/// The commitment can be persisted with `save` before the handle is set up from it, and the
+/// handle reloaded with `load`. The saved data records the `MembersCommitment` it was saved for,
+/// so `load` refuses it for a different ring.
+///
+/// Neither `new` nor `save` can check that the commitment was opened for the given `members`:
+/// the caller must pair them correctly, or proofs will not validate against `members`.
+#[cfg(feature = "std")]
+pub struct RingProverHandle {
+	members: MembersCommitment,
+	member_idx: u32,
+	ring_prover: bandersnatch_vrfs::ring::RingProver,
+}
+
+#[cfg(feature = "std")]
+impl RingProverHandle {
+	/// Set up the prover from a `commitment` created by `open` for the ring `members`.
+	pub fn new(
+		commitment: <BandersnatchVrfVerifiable16 as GenerateVerifiable>::Commitment,
+		members: MembersCommitment,
+	) -> Result<Self, VerifiableError> {
+		let (member_idx, prover_key) = commitment;
+		let kzg = kzg(members.log_domain)?;
+		if member_idx >= kzg.max_keyset_size() as u32 {
+			return Err(VerifiableError::MemberIndexOutOfRange);
+		}
+		let ring_prover = kzg.init_ring_prover(prover_key.0, member_idx as usize);
+		Ok(Self {
+			members,
+			member_idx,
+			ring_prover,
+		})
+	}
+
+	/// The ring for which this prover creates proofs.
+	pub fn members(&self) -> &MembersCommitment {
+		&self.members
+	}
+
+	/// The position of the prover's member within the ring.
+	pub fn member_idx(&self) -> u32 {
+		self.member_idx
+	}
+
+	/// Create a proof of membership just like `BandersnatchVrfVerifiable::create`.
+	///
+	/// `secret` must be the secret of the member for which the handle was opened.
+	pub fn create(
+		&self,
+		secret: &SecretKey,
+		context: &[u8],
+		message: &[u8],
+	) -> Result<([u8; RING_SIGNATURE_SIZE], Alias), VerifiableError> {
+		ring_prove_one(&self.ring_prover, secret, context, message)
+	}
+
+	/// Create a proof of membership in several contexts at once, just like
+	/// `BandersnatchVrfVerifiable::create_multi`.
+	pub fn create_multi<const N: usize>(
+		&self,
+		secret: &SecretKey,
+		contexts: [&[u8]; N],
+		message: &[u8],
+	) -> Result<(Vec<u8>, [Alias; N]), VerifiableError> {
+		ring_prove(&self.ring_prover, secret, contexts, message)
+	}
+
+	/// Serialize a `commitment` created by `open` for the ring `members`, so that a handle may be
+	/// set up from it later with `load`.
+	///
+	/// The handle does not keep the prover key around once set up, so this is done with the
+	/// commitment rather than with a handle: save it before passing it on to `new`.
+	pub fn save(
+		commitment: &<BandersnatchVrfVerifiable16 as GenerateVerifiable>::Commitment,
+		members: &MembersCommitment,
+	) -> Vec<u8> {
+		(members, commitment).encode()
+	}
+
+	/// Set up a handle from a commitment previously serialized with `save` for the ring `members`.
+	///
+	/// Fails with `VerifiableError::MembersMismatch` if `data` was saved for another ring. This only
+	/// compares `members` with the ring recorded by `save`; see the pairing note on the type.
+	pub fn load(mut data: &[u8], members: &MembersCommitment) -> Result<Self, VerifiableError> {
+		let (saved_members, commitment) = <(
+			MembersCommitment,
+			<BandersnatchVrfVerifiable16 as GenerateVerifiable>::Commitment,
+		)>::decode(&mut data)
+		.map_err(|_| VerifiableError::Serialization)?;
+		if &saved_members != members {
+			return Err(VerifiableError::MembersMismatch);
+		}
+		Self::new(commitment, saved_members)
+	}
+}
+
+/// The Ring-VRF, for rings over the domain of size `2^LOG_DOMAIN`, which must be one of the
+/// `LOG_DOMAINS`: see `BandersnatchVrfVerifiable9` and `BandersnatchVrfVerifiable16`.
+///
+/// The domain is recorded in the `Members` and `Intermediate` values, so that values of one
+/// domain are rejected with `VerifiableError::DomainMismatch` by the other.
+pub struct BandersnatchVrfVerifiable<const LOG_DOMAIN: u32>;
+
+/// The Ring-VRF over the small domain, whose ring data is shipped with the sources.
+pub type BandersnatchVrfVerifiable9 = BandersnatchVrfVerifiable<9>;
+
+/// The Ring-VRF over the domain of `2^16` members.
+pub type BandersnatchVrfVerifiable16 = BandersnatchVrfVerifiable<16>;
+
+impl<const LOG_DOMAIN: u32> BandersnatchVrfVerifiable<LOG_DOMAIN> {
+	/// The size of the ring domain.
+	pub const DOMAIN_SIZE: usize = 1 << LOG_DOMAIN;
+
+	const EMPTY_RING: RingCommitment = empty_ring(LOG_DOMAIN);
+
+	fn check_domain(log_domain: u32) -> Result<(), VerifiableError> {
+		if log_domain != LOG_DOMAIN {
+			return Err(VerifiableError::DomainMismatch);
+		}
+		Ok(())
+	}
 
-impl BandersnatchVrfVerifiable {
 	pub fn start_members_from_params(
 		vk: KzgVk,
 		srs: impl Fn(Range<usize>) -> Result<Vec<bls12_381::G1Affine>, ()>,
 	) -> MembersSet {
-		let piop_params = bandersnatch_vrfs::ring::make_piop_params(DOMAIN_SIZE);
+		let piop_params = bandersnatch_vrfs::ring::make_piop_params(Self::DOMAIN_SIZE);
 		let ring = RingCommitment::empty(&piop_params, srs, vk.g1.into());
 		MembersSet {
 			ring,
 			kzg_raw_vk: vk,
+			log_domain: LOG_DOMAIN,
 		}
 	}
+
+	/// Like `open`, but for a `member` whose position `member_idx` in the ring is already known
+	/// and with the ring's members supplied page by page, e.g. straight from paged storage.
+	///
+	/// This only skips the search for `member`: the keys of the whole ring are still gathered and
+	/// the commitment is the prover key of the ring (~49 MB for domain 16), so it takes
+	/// as much memory as `open`. See `write_ring_keys` for keeping the ring on disk instead.
+	#[cfg(feature = "std")]
+	pub fn open_at(
+		member: &<Self as GenerateVerifiable>::Member,
+		member_idx: u32,
+		pages: impl Iterator<Item = Vec<<Self as GenerateVerifiable>::Member>>,
+	) -> Result<<Self as GenerateVerifiable>::Commitment, VerifiableError> {
+		let kzg = kzg(LOG_DOMAIN)?;
+		if member_idx as usize >= kzg.max_keyset_size() {
+			return Err(VerifiableError::MemberIndexOutOfRange);
+		}
+		let mut pks = Vec::new();
+		for page in pages {
+			pks.extend(page.into_iter().map(|m| m.0 .0));
+		}
+		match pks.get(member_idx as usize) {
+			Some(pk) if *pk == member.0 .0 => {}
+			Some(_) => return Err(VerifiableError::NotMember),
+			None => return Err(VerifiableError::MemberIndexOutOfRange),
+		}
+		let prover_key = kzg.prover_key(pks);
+		Ok((member_idx, prover_key.into()))
+	}
+
+	/// Like `open_at`, but writing the ring keys file of `member` to `out` rather than building
+	/// the prover key of the ring: `member_idx` as a little-endian `u32`, then the (32 byte)
+	/// encodings of the ring's members.
+	///
+	/// This is not a commitment: `bandersnatch_vrfs` only proves from the full prover key of the
+	/// ring, so there is no commitment which does not grow with it. The file takes 32 bytes per
+	/// member, so it may be kept on disk between proofs, but `open_ring_keys` still builds the
+	/// whole prover key and the peak memory of proving is that of `open`.
+	///
+	/// Only one page of members is held at a time. On error, `out` holds a partial write.
+	#[cfg(feature = "std")]
+	pub fn write_ring_keys(
+		member: &<Self as GenerateVerifiable>::Member,
+		member_idx: u32,
+		pages: impl Iterator<Item = Vec<<Self as GenerateVerifiable>::Member>>,
+		out: &mut impl std::io::Write,
+	) -> Result<(), VerifiableError> {
+		let capacity = <Self as GenerateVerifiable>::capacity();
+		if member_idx as usize >= capacity {
+			return Err(VerifiableError::MemberIndexOutOfRange);
+		}
+		out.write_all(&member_idx.to_le_bytes())
+			.map_err(|_| VerifiableError::Serialization)?;
+		let mut count = 0;
+		for page in pages {
+			for m in page {
+				if count == capacity {
+					return Err(VerifiableError::RingFull);
+				}
+				if count == member_idx as usize && m != *member {
+					return Err(VerifiableError::NotMember);
+				}
+				out.write_all(&m.encode())
+					.map_err(|_| VerifiableError::Serialization)?;
+				count += 1;
+			}
+		}
+		if count <= member_idx as usize {
+			return Err(VerifiableError::MemberIndexOutOfRange);
+		}
+		Ok(())
+	}
+
+	/// The commitment for `create` from a ring keys file written by `write_ring_keys`.
+	///
+	/// Files with more keys than `capacity()` are rejected, so a corrupted (or foreign) file
+	/// cannot make this build an oversized prover key.
+	#[cfg(feature = "std")]
+	pub fn open_ring_keys(
+		ring_keys: &[u8],
+	) -> Result<<Self as GenerateVerifiable>::Commitment, VerifiableError> {
+		let (member_idx, keys) = ring_keys
+			.split_first_chunk::<4>()
+			.ok_or(VerifiableError::Serialization)?;
+		let member_idx = u32::from_le_bytes(*member_idx);
+		if keys.len() % 32 != 0 {
+			return Err(VerifiableError::Serialization);
+		}
+		if keys.len() / 32 > <Self as GenerateVerifiable>::capacity() {
+			return Err(VerifiableError::RingFull);
+		}
+		let pks = keys
+			.chunks_exact(32)
+			.map(|mut key| {
+				<Self as GenerateVerifiable>::Member::decode(&mut key)
+					.map(|m| m.0 .0)
+					.map_err(|_| VerifiableError::MalformedMember)
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		if member_idx as usize >= pks.len() {
+			return Err(VerifiableError::MemberIndexOutOfRange);
+		}
+		let prover_key = kzg(LOG_DOMAIN)?.prover_key(pks);
+		Ok((member_idx, prover_key.into()))
+	}
+
+	/// Like `create`, but proving membership in all of the given `contexts` at once: the proof
+	/// shows that the same (anonymous) member has each of the returned aliases, one per context.
+	///
+	/// The proof grows with the number of contexts, so it is returned as a `Vec<u8>`.
+	#[cfg(feature = "std")]
+	pub fn create_multi<const N: usize>(
+		commitment: <Self as GenerateVerifiable>::Commitment,
+		secret: &SecretKey,
+		contexts: [&[u8]; N],
+		message: &[u8],
+	) -> Result<(Vec<u8>, [Alias; N]), VerifiableError> {
+		let (prover_idx, prover_key) = commitment;
+		let kzg = kzg(LOG_DOMAIN)?;
+		if prover_idx >= kzg.max_keyset_size() as u32 {
+			return Err(VerifiableError::MemberIndexOutOfRange);
+		}
+		let ring_prover = kzg.init_ring_prover(prover_key.0, prover_idx as usize);
+		ring_prove(&ring_prover, secret, contexts, message)
+	}
+
+	/// Like `validate`, but for a proof created by `create_multi`, returning the alias of the
+	/// member in each of `contexts` (which must be given in the same order).
+	pub fn validate_multi<const N: usize>(
+		proof: &[u8],
+		members: &MembersCommitment,
+		contexts: [&[u8]; N],
+		message: &[u8],
+	) -> Result<[Alias; N], VerifiableError> {
+		Self::check_domain(members.log_domain)?;
+		PreparedMembers::new(members.clone())?.validate_multi(proof, contexts, message)
+	}
 }
 
-impl GenerateVerifiable for BandersnatchVrfVerifiable {
+impl<const LOG_DOMAIN: u32> GenerateVerifiable for BandersnatchVrfVerifiable<LOG_DOMAIN> {
 	type Members = MembersCommitment;
 	type Intermediate = MembersSet;
 	type Member = ArkScale<PublicKey>;
@@ -142,25 +899,110 @@ impl GenerateVerifiable for BandersnatchVrfVerifiable {
 
 	fn start_members() -> Self::Intermediate {
 		MembersSet {
-			ring: EMPTY_RING,
+			ring: Self::EMPTY_RING,
 			kzg_raw_vk: bandersnatch_vrfs::zcash_consts::ZCASH_KZG_VK,
+			log_domain: LOG_DOMAIN,
 		}
 	}
 
+	fn member_count(intermediate: &Self::Intermediate) -> usize {
+		intermediate.ring.curr_keys
+	}
+
+	fn capacity() -> usize {
+		Self::EMPTY_RING.max_keys
+	}
+
 	fn push_member(
 		intermediate: &mut Self::Intermediate,
 		who: Self::Member,
 		lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
-	) -> Result<(), ()> {
-		intermediate
-			.ring
-			.append(&[who.0 .0], |range| Ok(vec![lookup(range.start)?.0]));
+	) -> Result<(), VerifiableError> {
+		Self::check_domain(intermediate.log_domain)?;
+		if intermediate.ring.curr_keys >= intermediate.ring.max_keys {
+			return Err(VerifiableError::RingFull);
+		}
+		// `append` panics if the lookup fails, so fetch the chunk beforehand.
+		let chunk =
+			lookup(intermediate.ring.curr_keys).map_err(|_| VerifiableError::LookupFailed)?;
+		intermediate.ring.append(&[who.0 .0], |_| Ok(vec![chunk.0]));
+		Ok(())
+	}
+
+	fn push_members(
+		intermediate: &mut Self::Intermediate,
+		members: impl Iterator<Item = Self::Member>,
+		lookup: impl Fn(Range<usize>) -> Result<Vec<Self::StaticChunk>, ()>,
+	) -> Result<(), VerifiableError> {
+		Self::check_domain(intermediate.log_domain)?;
+		let keys: Vec<_> = members.map(|m| m.0 .0).collect();
+		if keys.is_empty() {
+			return Ok(());
+		}
+		let start = intermediate.ring.curr_keys;
+		if start + keys.len() > intermediate.ring.max_keys {
+			return Err(VerifiableError::RingFull);
+		}
+		let chunks =
+			lookup(start..start + keys.len()).map_err(|_| VerifiableError::LookupFailed)?;
+		if chunks.len() != keys.len() {
+			return Err(VerifiableError::LookupFailed);
+		}
+		let bases: Vec<_> = chunks.into_iter().map(|c| c.0).collect();
+		// A single MSM over the whole batch.
+		intermediate.ring.append(&keys, |_| Ok(bases.clone()));
+		Ok(())
+	}
+
+	fn replace_member(
+		intermediate: &mut Self::Intermediate,
+		index: usize,
+		who: Self::Member,
+		new: Self::Member,
+		lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
+	) -> Result<(), VerifiableError> {
+		Self::check_domain(intermediate.log_domain)?;
+		if index >= intermediate.ring.curr_keys {
+			return Err(VerifiableError::MemberIndexOutOfRange);
+		}
+		let base = lookup(index).map_err(|_| VerifiableError::LookupFailed)?;
+		update_ring_slot(&mut intermediate.ring, base.0, &who.0, &new.0);
+		Ok(())
+	}
+
+	fn remove_member(
+		intermediate: &mut Self::Intermediate,
+		index: usize,
+		who: Self::Member,
+		last: Self::Member,
+		lookup: impl Fn(usize) -> Result<Self::StaticChunk, ()>,
+	) -> Result<(), VerifiableError> {
+		Self::check_domain(intermediate.log_domain)?;
+		let ring = &mut intermediate.ring;
+		if index >= ring.curr_keys {
+			return Err(VerifiableError::MemberIndexOutOfRange);
+		}
+		let last_index = ring.curr_keys - 1;
+		let base = lookup(index).map_err(|_| VerifiableError::LookupFailed)?;
+		// Unused positions of the ring hold the padding point.
+		let padding = PublicKey(ring.padding_point);
+		if index == last_index {
+			update_ring_slot(ring, base.0, &who.0, &padding);
+		} else {
+			let last_base = lookup(last_index).map_err(|_| VerifiableError::LookupFailed)?;
+			update_ring_slot(ring, base.0, &who.0, &last.0);
+			update_ring_slot(ring, last_base.0, &last.0, &padding);
+		}
+		ring.curr_keys = last_index;
 		Ok(())
 	}
 
 	fn finish_members(inter: Self::Intermediate) -> Self::Members {
 		let verifier_key = VerifierKey::from_ring_and_kzg_vk(&inter.ring, inter.kzg_raw_vk);
-		MembersCommitment(verifier_key)
+		MembersCommitment {
+			verifier_key,
+			log_domain: inter.log_domain,
+		}
 	}
 
 	fn new_secret(entropy: Entropy) -> Self::Secret {
@@ -176,37 +1018,21 @@ impl GenerateVerifiable for BandersnatchVrfVerifiable {
 		members: &Self::Members,
 		context: &[u8],
 		message: &[u8],
-	) -> Result<Alias, ()> {
+	) -> Result<Alias, VerifiableError> {
 		// This doesn't require the whole kzg. Thus is more appropriate if used on-chain
 		// Is a bit slower as it requires to recompute piop_params, but still in the order of ms
-		let ring_verifier =
-			bandersnatch_vrfs::ring::make_ring_verifier(members.0.clone(), DOMAIN_SIZE);
-
-		let vrf_input = Message {
-			domain: VRF_INPUT_DOMAIN,
-			message: context,
-		}
-		.into_vrf_input();
-
-		let ring_signature =
-			RingVrfSignature::deserialize_compressed(proof.as_slice()).map_err(|_| ())?;
-
-		let ios = RingVerifier(&ring_verifier)
-			.verify_ring_vrf(message, core::iter::once(vrf_input), &ring_signature)
-			.map_err(|_| ())?;
-
-		let alias: Alias = ios[0].vrf_output_bytes(VRF_OUTPUT_DOMAIN);
-		Ok(alias)
+		Self::check_domain(members.log_domain)?;
+		PreparedMembers::new(members.clone())?.validate(proof, context, message)
 	}
 
-	fn sign(secret: &Self::Secret, message: &[u8]) -> Result<Self::Signature, ()> {
+	fn sign(secret: &Self::Secret, message: &[u8]) -> Result<Self::Signature, VerifiableError> {
 		let mut transcript = Transcript::new_labeled(THIN_SIGNATURE_CONTEXT);
 		transcript.append_slice(message);
 		let signature = secret.sign_thin_vrf(transcript, &[]);
 		let mut raw = [0u8; THIN_SIGNATURE_SIZE];
 		signature
 			.serialize_compressed(raw.as_mut_slice())
-			.map_err(|_| ())?;
+			.map_err(|_| VerifiableError::Serialization)?;
 		Ok(raw)
 	}
 
@@ -215,8 +1041,10 @@ impl GenerateVerifiable for BandersnatchVrfVerifiable {
 		message: &[u8],
 		member: &Self::Member,
 	) -> bool {
-		let signature: ThinVrfSignature =
-			ThinVrfSignature::deserialize_compressed(signature.as_slice()).unwrap();
+		let Ok(signature) = ThinVrfSignature::<0>::deserialize_compressed(signature.as_slice())
+		else {
+			return false;
+		};
 		let mut transcript = Transcript::new_labeled(THIN_SIGNATURE_CONTEXT);
 		transcript.append_slice(message);
 		member
@@ -229,11 +1057,14 @@ impl GenerateVerifiable for BandersnatchVrfVerifiable {
 	fn open(
 		member: &Self::Member,
 		members: impl Iterator<Item = Self::Member>,
-	) -> Result<Self::Commitment, ()> {
+	) -> Result<Self::Commitment, VerifiableError> {
 		let pks: Vec<_> = members.map(|m| m.0 .0).collect();
-		let member_idx = pks.iter().position(|&m| m == member.0 .0).ok_or(())?;
+		let member_idx = pks
+			.iter()
+			.position(|&m| m == member.0 .0)
+			.ok_or(VerifiableError::NotMember)?;
 		let member_idx = member_idx as u32;
-		let prover_key = kzg().prover_key(pks);
+		let prover_key = kzg(LOG_DOMAIN)?.prover_key(pks);
 		Ok((member_idx, prover_key.into()))
 	}
 
@@ -241,8 +1072,8 @@ impl GenerateVerifiable for BandersnatchVrfVerifiable {
 	fn open(
 		_member: &Self::Member,
 		_members: impl Iterator<Item = Self::Member>,
-	) -> Result<Self::Commitment, ()> {
-		panic!("Not implemented")
+	) -> Result<Self::Commitment, VerifiableError> {
+		Err(VerifiableError::Unsupported)
 	}
 
 	#[cfg(feature = "std")]
@@ -251,46 +1082,193 @@ impl GenerateVerifiable for BandersnatchVrfVerifiable {
 		secret: &Self::Secret,
 		context: &[u8],
 		message: &[u8],
-	) -> Result<(Self::Proof, Alias), ()> {
+	) -> Result<(Self::Proof, Alias), VerifiableError> {
 		let (prover_idx, prover_key) = commitment;
-		if prover_idx >= kzg().max_keyset_size() as u32 {
-			return Err(());
+		let kzg = kzg(LOG_DOMAIN)?;
+		if prover_idx >= kzg.max_keyset_size() as u32 {
+			return Err(VerifiableError::MemberIndexOutOfRange);
 		}
 
-		let ring_prover = kzg().init_ring_prover(prover_key.0, prover_idx as usize);
+		let ring_prover = kzg.init_ring_prover(prover_key.0, prover_idx as usize);
+		ring_prove_one(&ring_prover, secret, context, message)
+	}
 
-		let vrf_input = Message {
-			domain: VRF_INPUT_DOMAIN,
-			message: context,
-		}
-		.into_vrf_input();
+	#[cfg(not(feature = "std"))]
+	fn create(
+		_commitment: Self::Commitment,
+		_secret: &Self::Secret,
+		_context: &[u8],
+		_message: &[u8],
+	) -> Result<(Self::Proof, Alias), VerifiableError> {
+		Err(VerifiableError::Unsupported)
+	}
+}
 
-		let ios = [secret.vrf_inout(vrf_input)];
+impl<const LOG_DOMAIN: u32> LinkableAliases for BandersnatchVrfVerifiable<LOG_DOMAIN> {
+	/// A ring proof with the VRF inputs of both contexts.
+	type LinkProof = [u8; LINK_PROOF_SIZE];
 
-		let signature: RingVrfSignature = RingProver {
-			ring_prover: &ring_prover,
-			secret,
-		}
-		.sign_ring_vrf(message, &ios);
+	#[cfg(feature = "std")]
+	fn prove_alias_link(
+		commitment: Self::Commitment,
+		secret: &Self::Secret,
+		context_a: &[u8],
+		context_b: &[u8],
+	) -> Result<(Self::LinkProof, Alias, Alias), VerifiableError> {
+		let (proof, [alias_a, alias_b]) =
+			Self::create_multi(commitment, secret, [context_a, context_b], LINK_MESSAGE)?;
+		let proof = proof
+			.try_into()
+			.map_err(|_| VerifiableError::Serialization)?;
+		Ok((proof, alias_a, alias_b))
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn prove_alias_link(
+		_commitment: Self::Commitment,
+		_secret: &Self::Secret,
+		_context_a: &[u8],
+		_context_b: &[u8],
+	) -> Result<(Self::LinkProof, Alias, Alias), VerifiableError> {
+		Err(VerifiableError::Unsupported)
+	}
 
-		let mut buf = [0u8; RING_SIGNATURE_SIZE];
+	fn verify_alias_link(
+		proof: &Self::LinkProof,
+		members: &Self::Members,
+		context_a: &[u8],
+		alias_a: &Alias,
+		context_b: &[u8],
+		alias_b: &Alias,
+	) -> bool {
+		Self::validate_multi(proof, members, [context_a, context_b], LINK_MESSAGE)
+			== Ok([*alias_a, *alias_b])
+	}
+}
+
+impl<const LOG_DOMAIN: u32> AliasOwnership for BandersnatchVrfVerifiable<LOG_DOMAIN> {
+	/// A thin-VRF signature (like `Signature`) with the VRF input of the context.
+	type OwnershipProof = [u8; OWNERSHIP_PROOF_SIZE];
+
+	fn prove_alias_ownership(
+		secret: &Self::Secret,
+		context: &[u8],
+	) -> Result<(Self::OwnershipProof, Alias), VerifiableError> {
+		let io = secret.vrf_inout(vrf_input(context));
+		let alias = io.vrf_output_bytes(VRF_OUTPUT_DOMAIN);
+		let signature = secret.sign_thin_vrf(Transcript::new_labeled(OWNERSHIP_CONTEXT), &[io]);
+		let mut raw = [0u8; OWNERSHIP_PROOF_SIZE];
 		signature
-			.serialize_compressed(buf.as_mut_slice())
-			.map_err(|_| ())?;
+			.serialize_compressed(raw.as_mut_slice())
+			.map_err(|_| VerifiableError::Serialization)?;
+		Ok((raw, alias))
+	}
 
-		let alias: Alias = ios[0].vrf_output_bytes(VRF_OUTPUT_DOMAIN);
+	fn verify_alias_ownership(
+		proof: &Self::OwnershipProof,
+		member: &Self::Member,
+		context: &[u8],
+		alias: &Alias,
+	) -> bool {
+		let Ok(signature) = ThinVrfSignature::<1>::deserialize_compressed(proof.as_slice()) else {
+			return false;
+		};
+		let Ok(ios) = member.0.verify_thin_vrf(
+			Transcript::new_labeled(OWNERSHIP_CONTEXT),
+			core::iter::once(vrf_input(context)),
+			&signature,
+		) else {
+			return false;
+		};
+		&ios[0].vrf_output_bytes(VRF_OUTPUT_DOMAIN) == alias
+	}
+}
 
-		Ok((buf, alias))
+/// Fixed input/output vectors of `BandersnatchVrfVerifiable`, for checking ports to other
+/// languages against and for catching changes to transcripts, domains or serialization.
+///
+/// The vectors are written to `ring-data/test-vectors-zcash-9.scale` by the
+/// `generate-test-vectors` binary. They are not checked in yet, so replaying them is an ignored
+/// test until they are.
+#[cfg(feature = "std")]
+pub mod test_vectors {
+	use super::*;
+
+	/// Context of the proofs in the vectors.
+	pub const CONTEXT: &[u8] = b"VerifiableTestVectorsContext";
+
+	/// Vectors of a ring built from all of the `vectors`' members, in order.
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug)]
+	pub struct TestVectors {
+		/// Encoding of the `Members` value of the ring.
+		pub members: Vec<u8>,
+		pub vectors: Vec<TestVector>,
 	}
 
-	#[cfg(not(feature = "std"))]
-	fn create(
-		_commitment: Self::Commitment,
-		_secret: &Self::Secret,
-		_context: &[u8],
-		_message: &[u8],
-	) -> Result<(Self::Proof, Alias), ()> {
-		panic!("Not implemented")
+	/// Vectors of a single member of the ring.
+	#[derive(Clone, Eq, PartialEq, Encode, Decode, Debug)]
+	pub struct TestVector {
+		/// Entropy passed to `new_secret`.
+		pub entropy: Entropy,
+		/// Encoding of the `Member` given by `member_from_secret`.
+		pub member: Vec<u8>,
+		/// Message which is both signed and proven.
+		pub message: Vec<u8>,
+		/// Encoding of the `Signature` of `message` given by `sign`.
+		pub signature: Vec<u8>,
+		/// Encoding of the `Proof` given by `create` for `CONTEXT` and `message`.
+		pub proof: Vec<u8>,
+		/// The `Alias` of the member in `CONTEXT`.
+		pub alias: Alias,
+	}
+
+	/// Generate the vectors for `count` members of a ring of the domain of size `2^LOG_DOMAIN`,
+	/// using `lookup` to build the ring.
+	pub fn generate<const LOG_DOMAIN: u32>(
+		count: u8,
+		lookup: impl Fn(Range<usize>) -> Result<Vec<bls12_381::G1Affine>, ()>,
+	) -> Result<TestVectors, VerifiableError> {
+		let entropies: Vec<Entropy> = (0..count).map(|i| [i; 32]).collect();
+		let secrets: Vec<_> = entropies
+			.iter()
+			.map(|e| BandersnatchVrfVerifiable::<LOG_DOMAIN>::new_secret(*e))
+			.collect();
+		let members: Vec<_> = secrets
+			.iter()
+			.map(BandersnatchVrfVerifiable::<LOG_DOMAIN>::member_from_secret)
+			.collect();
+
+		let mut inter = BandersnatchVrfVerifiable::<LOG_DOMAIN>::start_members();
+		BandersnatchVrfVerifiable::<LOG_DOMAIN>::push_members(
+			&mut inter,
+			members.iter().cloned(),
+			|range| Ok(lookup(range)?.into_iter().map(ArkScale).collect()),
+		)?;
+		let ring = BandersnatchVrfVerifiable::<LOG_DOMAIN>::finish_members(inter);
+
+		let mut vectors = Vec::with_capacity(count as usize);
+		for (i, (secret, member)) in secrets.iter().zip(members.iter()).enumerate() {
+			let message = (b"VerifiableTestVectorsMessage", i as u32).encode();
+			let signature = BandersnatchVrfVerifiable::<LOG_DOMAIN>::sign(secret, &message)?;
+			let commitment =
+				BandersnatchVrfVerifiable::<LOG_DOMAIN>::open(member, members.iter().cloned())?;
+			let (proof, alias) = BandersnatchVrfVerifiable::<LOG_DOMAIN>::create(
+				commitment, secret, CONTEXT, &message,
+			)?;
+			vectors.push(TestVector {
+				entropy: entropies[i],
+				member: member.encode(),
+				message,
+				signature: signature.encode(),
+				proof: proof.encode(),
+				alias,
+			});
+		}
+
+		Ok(TestVectors {
+			members: ring.encode(),
+			vectors,
+		})
 	}
 }
 
@@ -300,52 +1278,689 @@ mod tests {
 
 	use super::*;
 
-	#[cfg(feature = "small-ring")]
 	const ONCHAIN_VK: &[u8] = include_bytes!("ring-data/zcash-9.vk");
-	#[cfg(not(feature = "small-ring"))]
-	const ONCHAIN_VK: &[u8] = include_bytes!("ring-data/zcash-16.vk");
+	const OFFCHAIN_PK: &[u8] = include_bytes!("ring-data/zcash-9.pk");
+
+	type Member = <BandersnatchVrfVerifiable9 as GenerateVerifiable>::Member;
+	type StaticChunk = <BandersnatchVrfVerifiable9 as GenerateVerifiable>::StaticChunk;
+
+	/// Install the prover parameters, unless they already are (or are embedded).
+	fn init_params() {
+		let _ = RingParams::from_bytes_unchecked(OFFCHAIN_PK)
+			.unwrap()
+			.install();
+	}
+
+	/// The Lagrange basis commitments of the verifier key, decoded once.
+	fn lag_g1() -> &'static [bls12_381::G1Affine] {
+		static LAG_G1: std::sync::OnceLock<Vec<bls12_381::G1Affine>> = std::sync::OnceLock::new();
+		LAG_G1.get_or_init(|| {
+			StaticVerifierKey::deserialize_uncompressed_unchecked(ONCHAIN_VK)
+				.unwrap()
+				.lag_g1
+		})
+	}
+
+	/// The `lookup` of `push_member`, `replace_member` and `remove_member`.
+	fn lookup(i: usize) -> Result<StaticChunk, ()> {
+		lag_g1().get(i).map(|p| ArkScale(*p)).ok_or(())
+	}
+
+	/// The `lookup` of `push_members`.
+	fn lookup_range(range: Range<usize>) -> Result<Vec<StaticChunk>, ()> {
+		Ok(lag_g1()
+			.get(range)
+			.ok_or(())?
+			.iter()
+			.cloned()
+			.map(ArkScale)
+			.collect())
+	}
+
+	/// `n` members, the secret of the `i`th one made from `[i; 32]`.
+	fn members_of(n: u8) -> (Vec<SecretKey>, Vec<Member>) {
+		let secrets: Vec<_> = (0..n)
+			.map(|i| BandersnatchVrfVerifiable9::new_secret([i; 32]))
+			.collect();
+		let members = secrets
+			.iter()
+			.map(BandersnatchVrfVerifiable9::member_from_secret)
+			.collect();
+		(secrets, members)
+	}
+
+	/// The ring of `members`, in order.
+	fn build_ring(members: &[Member]) -> MembersCommitment {
+		let mut inter = BandersnatchVrfVerifiable9::start_members();
+		BandersnatchVrfVerifiable9::push_members(&mut inter, members.iter().cloned(), lookup_range)
+			.unwrap();
+		BandersnatchVrfVerifiable9::finish_members(inter)
+	}
+
+	/// The secrets, members and ring of `n` members, as given by `members_of`.
+	fn ring_of(n: u8) -> (Vec<SecretKey>, Vec<Member>, MembersCommitment) {
+		let (secrets, members) = members_of(n);
+		let ring = build_ring(&members);
+		(secrets, members, ring)
+	}
+
+	#[test]
+	fn ring_params_are_checked() {
+		assert!(matches!(
+			RingParams::from_bytes(&OFFCHAIN_PK[..100]),
+			Err(VerifiableError::InvalidParams)
+		));
+		assert!(matches!(
+			RingParams::from_bytes(ONCHAIN_VK),
+			Err(VerifiableError::InvalidParams)
+		));
+		assert!(matches!(
+			RingParams::load_prover_params("/nonexistent/zcash-9.pk"),
+			Err(VerifiableError::ParamsUnavailable)
+		));
+		let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/ring-data/zcash-9.pk");
+		assert_eq!(
+			RingParams::load_prover_params(path).unwrap().log_domain(),
+			9
+		);
+	}
+
+	#[test]
+	fn corrupt_ring_params_are_rejected() {
+		let zcash_vk = &zcash_consts::ZCASH_KZG_VK;
+		assert_eq!(RingParams::from_bytes(OFFCHAIN_PK).unwrap().log_domain(), 9);
+		assert!(RingParams::from_bytes_with_vk(OFFCHAIN_PK, zcash_vk).is_ok());
+
+		// A point off the curve.
+		let mut corrupt = OFFCHAIN_PK.to_vec();
+		corrupt[8 + 96 * 10 + 40] ^= 1;
+		assert!(matches!(
+			RingParams::from_bytes(&corrupt),
+			Err(VerifiableError::InvalidParams)
+		));
+		assert!(matches!(
+			RingParams::from_bytes_with_vk(&corrupt, zcash_vk),
+			Err(VerifiableError::InvalidParams)
+		));
+
+		// Valid points, but not the powers of tau.
+		let mut swapped = OFFCHAIN_PK.to_vec();
+		let (first, second) = (8 + 96 * 10, 8 + 96 * 11);
+		let point: Vec<u8> = swapped[first..second].to_vec();
+		swapped.copy_within(second..second + 96, first);
+		swapped[second..second + 96].copy_from_slice(&point);
+		assert!(matches!(
+			RingParams::from_bytes_with_vk(&swapped, zcash_vk),
+			Err(VerifiableError::InvalidParams)
+		));
+
+		// Another tau in G2.
+		let mut other_vk = zcash_vk.clone();
+		other_vk.tau_in_g2 = other_vk.g2;
+		assert!(matches!(
+			RingParams::from_bytes_with_vk(OFFCHAIN_PK, &other_vk),
+			Err(VerifiableError::InvalidParams)
+		));
+	}
+
+	#[test]
+	fn lagrange_srs_provider_serves_vk() {
+		let vk = StaticVerifierKey::deserialize_uncompressed_unchecked(ONCHAIN_VK).unwrap();
+		let provider = LagrangeSrsProvider::new(ONCHAIN_VK).unwrap();
+		assert_eq!(provider.log_domain(), 9);
+		assert_eq!(provider.kzg_vk().unwrap(), vk.kzg_vk);
+		assert_eq!(provider.points(0..512), Ok(vk.lag_g1.clone()));
+		assert_eq!(provider.points(100..103), Ok(vk.lag_g1[100..103].to_vec()));
+		assert_eq!(provider.lookup()(511), Ok(ArkScale(vk.lag_g1[511])));
+		assert_eq!(provider.lookup()(512), Err(()));
+		assert_eq!(provider.lookup_range()(510..513), Err(()));
+
+		// Pages which do not line up with the points.
+		let paged = PagedBytes::new(1000, |i| {
+			ONCHAIN_VK.chunks(1000).nth(i).map(<[u8]>::to_vec).ok_or(())
+		});
+		let provider = LagrangeSrsProvider::new(paged).unwrap();
+		assert_eq!(provider.kzg_vk().unwrap(), vk.kzg_vk);
+		assert_eq!(provider.points(0..512), Ok(vk.lag_g1.clone()));
+		assert_eq!(provider.lookup()(10), Ok(ArkScale(vk.lag_g1[10])));
+
+		// Missing pages.
+		let missing = PagedBytes::new(1000, |i| {
+			if i == 3 {
+				return Err(());
+			}
+			ONCHAIN_VK.chunks(1000).nth(i).map(<[u8]>::to_vec).ok_or(())
+		});
+		let provider = LagrangeSrsProvider::new(missing).unwrap();
+		assert_eq!(provider.lookup()(0), Ok(ArkScale(vk.lag_g1[0])));
+		assert_eq!(provider.lookup()(30), Err(()));
+
+		// Truncated or otherwise malformed keys.
+		assert!(LagrangeSrsProvider::new(&ONCHAIN_VK[..ONCHAIN_VK.len() - 1]).is_err());
+		assert!(LagrangeSrsProvider::new(&ONCHAIN_VK[..4]).is_err());
+		assert!(LagrangeSrsProvider::new(OFFCHAIN_PK).is_err());
+	}
+
+	#[test]
+	fn lagrange_srs_provider_builds_rings() {
+		let provider = LagrangeSrsProvider::new(ONCHAIN_VK.to_vec()).unwrap();
+		let (_, members) = members_of(10);
+
+		let mut expected = BandersnatchVrfVerifiable9::start_members();
+		BandersnatchVrfVerifiable9::push_members(
+			&mut expected,
+			members.iter().cloned(),
+			lookup_range,
+		)
+		.unwrap();
+
+		let mut inter = BandersnatchVrfVerifiable9::start_members_from_params(
+			provider.kzg_vk().unwrap(),
+			provider.srs(),
+		);
+		assert_eq!(inter, BandersnatchVrfVerifiable9::start_members());
+		BandersnatchVrfVerifiable9::push_members(
+			&mut inter,
+			members[..5].iter().cloned(),
+			provider.lookup_range(),
+		)
+		.unwrap();
+		for member in &members[5..] {
+			BandersnatchVrfVerifiable9::push_member(&mut inter, member.clone(), provider.lookup())
+				.unwrap();
+		}
+		assert_eq!(inter, expected);
+	}
+
+	#[test]
+	fn domains_do_not_mix() {
+		init_params();
+		assert!(BandersnatchVrfVerifiable16::capacity() > BandersnatchVrfVerifiable9::capacity());
+
+		let secret = BandersnatchVrfVerifiable9::new_secret([0u8; 32]);
+		let member = BandersnatchVrfVerifiable9::member_from_secret(&secret);
+
+		let mut inter = BandersnatchVrfVerifiable9::start_members();
+		assert_eq!(
+			BandersnatchVrfVerifiable16::push_member(&mut inter, member.clone(), lookup),
+			Err(VerifiableError::DomainMismatch)
+		);
+		BandersnatchVrfVerifiable9::push_member(&mut inter, member.clone(), lookup).unwrap();
+		let ring = BandersnatchVrfVerifiable9::finish_members(inter);
+		assert_eq!(ring.log_domain(), 9);
+
+		let commitment =
+			BandersnatchVrfVerifiable9::open(&member, core::iter::once(member.clone())).unwrap();
+		let (proof, alias) =
+			BandersnatchVrfVerifiable9::create(commitment, &secret, b"ctx", b"msg").unwrap();
+		assert_eq!(
+			BandersnatchVrfVerifiable9::validate(&proof, &ring, b"ctx", b"msg"),
+			Ok(alias)
+		);
+		assert_eq!(
+			BandersnatchVrfVerifiable16::validate(&proof, &ring, b"ctx", b"msg"),
+			Err(VerifiableError::DomainMismatch)
+		);
+
+		// The domain is part of the encoding, so it cannot be changed unnoticed either.
+		let mut encoded = ring.encode();
+		let len = encoded.len();
+		encoded[len - 4..].copy_from_slice(&16u32.to_le_bytes());
+		let relabeled = MembersCommitment::decode(&mut &encoded[..]).unwrap();
+		assert_eq!(
+			BandersnatchVrfVerifiable9::validate(&proof, &relabeled, b"ctx", b"msg"),
+			Err(VerifiableError::DomainMismatch)
+		);
+	}
 
 	#[test]
 	fn start_push_finish() {
-		let alice_sec = BandersnatchVrfVerifiable::new_secret([0u8; 32]);
-		let bob_sec = BandersnatchVrfVerifiable::new_secret([1u8; 32]);
-		let charlie_sec = BandersnatchVrfVerifiable::new_secret([2u8; 32]);
+		let alice_sec = BandersnatchVrfVerifiable9::new_secret([0u8; 32]);
+		let bob_sec = BandersnatchVrfVerifiable9::new_secret([1u8; 32]);
+		let charlie_sec = BandersnatchVrfVerifiable9::new_secret([2u8; 32]);
 
-		let alice = BandersnatchVrfVerifiable::member_from_secret(&alice_sec);
-		let bob = BandersnatchVrfVerifiable::member_from_secret(&bob_sec);
-		let charlie = BandersnatchVrfVerifiable::member_from_secret(&charlie_sec);
+		let alice = BandersnatchVrfVerifiable9::member_from_secret(&alice_sec);
+		let bob = BandersnatchVrfVerifiable9::member_from_secret(&bob_sec);
+		let charlie = BandersnatchVrfVerifiable9::member_from_secret(&charlie_sec);
 
 		let vk = StaticVerifierKey::deserialize_uncompressed_unchecked(ONCHAIN_VK).unwrap();
-		let get_one = |i| Ok(ArkScale(vk.lag_g1[i]));
-		let get_many = |range: Range<usize>| Ok(vk.lag_g1[range].to_vec());
+		let srs = |range: Range<usize>| Ok(lag_g1()[range].to_vec());
 
-		let mut inter1 = BandersnatchVrfVerifiable::start_members();
-		let mut inter2 = BandersnatchVrfVerifiable::start_members_from_params(vk.kzg_vk, get_many);
+		let mut inter1 = BandersnatchVrfVerifiable9::start_members();
+		let mut inter2 = BandersnatchVrfVerifiable9::start_members_from_params(vk.kzg_vk, srs);
 		assert_eq!(inter1, inter2);
 
-		BandersnatchVrfVerifiable::push_member(&mut inter1, alice.clone(), get_one).unwrap();
-		BandersnatchVrfVerifiable::push_member(&mut inter2, alice.clone(), get_one).unwrap();
-		BandersnatchVrfVerifiable::push_member(&mut inter1, bob.clone(), get_one).unwrap();
-		BandersnatchVrfVerifiable::push_member(&mut inter2, bob.clone(), get_one).unwrap();
-		BandersnatchVrfVerifiable::push_member(&mut inter1, charlie.clone(), get_one).unwrap();
-		BandersnatchVrfVerifiable::push_member(&mut inter2, charlie.clone(), get_one).unwrap();
+		BandersnatchVrfVerifiable9::push_member(&mut inter1, alice.clone(), lookup).unwrap();
+		BandersnatchVrfVerifiable9::push_member(&mut inter2, alice.clone(), lookup).unwrap();
+		BandersnatchVrfVerifiable9::push_member(&mut inter1, bob.clone(), lookup).unwrap();
+		BandersnatchVrfVerifiable9::push_member(&mut inter2, bob.clone(), lookup).unwrap();
+		BandersnatchVrfVerifiable9::push_member(&mut inter1, charlie.clone(), lookup).unwrap();
+		BandersnatchVrfVerifiable9::push_member(&mut inter2, charlie.clone(), lookup).unwrap();
 		assert_eq!(inter1, inter2);
 
-		let members1 = BandersnatchVrfVerifiable::finish_members(inter1);
-		let members2 = BandersnatchVrfVerifiable::finish_members(inter2);
+		let members1 = BandersnatchVrfVerifiable9::finish_members(inter1);
+		let members2 = BandersnatchVrfVerifiable9::finish_members(inter2);
 		assert_eq!(members1, members2);
 	}
 
+	#[test]
+	fn push_members_matches_push_member() {
+		let (_, members) = members_of(10);
+
+		let mut inter1 = BandersnatchVrfVerifiable9::start_members();
+		for member in members.iter().cloned() {
+			BandersnatchVrfVerifiable9::push_member(&mut inter1, member, lookup).unwrap();
+		}
+
+		let mut inter2 = BandersnatchVrfVerifiable9::start_members();
+		BandersnatchVrfVerifiable9::push_members(
+			&mut inter2,
+			members[..4].iter().cloned(),
+			lookup_range,
+		)
+		.unwrap();
+		BandersnatchVrfVerifiable9::push_members(
+			&mut inter2,
+			members[4..].iter().cloned(),
+			lookup_range,
+		)
+		.unwrap();
+		assert_eq!(inter1, inter2);
+
+		let fail = |_: Range<usize>| Err(());
+		assert_eq!(
+			BandersnatchVrfVerifiable9::push_members(&mut inter2, members.iter().cloned(), fail),
+			Err(VerifiableError::LookupFailed)
+		);
+		assert_eq!(inter1, inter2);
+	}
+
+	#[test]
+	fn ring_capacity() {
+		let capacity = BandersnatchVrfVerifiable9::capacity();
+		let members: Vec<_> = (0..=capacity)
+			.map(|i| {
+				let mut entropy = [0u8; 32];
+				entropy[..8].copy_from_slice(&(i as u64).to_le_bytes());
+				let secret = BandersnatchVrfVerifiable9::new_secret(entropy);
+				BandersnatchVrfVerifiable9::member_from_secret(&secret)
+			})
+			.collect();
+
+		let mut inter = BandersnatchVrfVerifiable9::start_members();
+		assert_eq!(BandersnatchVrfVerifiable9::member_count(&inter), 0);
+		assert_eq!(
+			BandersnatchVrfVerifiable9::push_members(
+				&mut inter,
+				members.iter().cloned(),
+				lookup_range
+			),
+			Err(VerifiableError::RingFull)
+		);
+		assert_eq!(BandersnatchVrfVerifiable9::member_count(&inter), 0);
+
+		BandersnatchVrfVerifiable9::push_members(
+			&mut inter,
+			members[..capacity - 1].iter().cloned(),
+			lookup_range,
+		)
+		.unwrap();
+		assert_eq!(
+			BandersnatchVrfVerifiable9::member_count(&inter),
+			capacity - 1
+		);
+		assert!(!BandersnatchVrfVerifiable9::is_full(&inter));
+
+		BandersnatchVrfVerifiable9::push_member(&mut inter, members[capacity - 1].clone(), lookup)
+			.unwrap();
+		assert!(BandersnatchVrfVerifiable9::is_full(&inter));
+		assert_eq!(
+			BandersnatchVrfVerifiable9::push_member(&mut inter, members[capacity].clone(), lookup),
+			Err(VerifiableError::RingFull)
+		);
+	}
+
+	#[test]
+	fn remove_replace_matches_fresh_ring() {
+		let (_, members) = members_of(6);
+		let build = |set: &[usize]| {
+			let mut inter = BandersnatchVrfVerifiable9::start_members();
+			for &i in set {
+				BandersnatchVrfVerifiable9::push_member(&mut inter, members[i].clone(), lookup)
+					.unwrap();
+			}
+			inter
+		};
+
+		let mut inter = build(&[0, 1, 2, 3, 4]);
+		BandersnatchVrfVerifiable9::replace_member(
+			&mut inter,
+			2,
+			members[2].clone(),
+			members[5].clone(),
+			lookup,
+		)
+		.unwrap();
+		assert_eq!(inter, build(&[0, 1, 5, 3, 4]));
+
+		BandersnatchVrfVerifiable9::remove_member(
+			&mut inter,
+			1,
+			members[1].clone(),
+			members[4].clone(),
+			lookup,
+		)
+		.unwrap();
+		assert_eq!(inter, build(&[0, 4, 5, 3]));
+
+		BandersnatchVrfVerifiable9::remove_member(
+			&mut inter,
+			3,
+			members[3].clone(),
+			members[3].clone(),
+			lookup,
+		)
+		.unwrap();
+		assert_eq!(inter, build(&[0, 4, 5]));
+
+		// Members can be pushed again after a removal.
+		BandersnatchVrfVerifiable9::push_member(&mut inter, members[1].clone(), lookup).unwrap();
+		assert_eq!(inter, build(&[0, 4, 5, 1]));
+		assert_eq!(
+			BandersnatchVrfVerifiable9::finish_members(inter),
+			BandersnatchVrfVerifiable9::finish_members(build(&[0, 4, 5, 1]))
+		);
+	}
+
+	#[test]
+	#[ignore = "needs the vectors written by `cargo run --bin generate-test-vectors`"]
+	fn replay_test_vectors() {
+		use test_vectors::*;
+
+		init_params();
+
+		let path = concat!(
+			env!("CARGO_MANIFEST_DIR"),
+			"/src/ring-data/test-vectors-zcash-9.scale"
+		);
+		let data = std::fs::read(path).expect(
+			"missing test vectors, generate them with \
+			`cargo run --bin generate-test-vectors`",
+		);
+		let vectors = TestVectors::decode(&mut &data[..]).unwrap();
+
+		let mut members = Vec::new();
+		for vector in &vectors.vectors {
+			let secret = BandersnatchVrfVerifiable9::new_secret(vector.entropy);
+			let member = BandersnatchVrfVerifiable9::member_from_secret(&secret);
+			assert_eq!(member.encode(), vector.member);
+			members.push(member);
+		}
+
+		let ring = build_ring(&members);
+		assert_eq!(ring.encode(), vectors.members);
+
+		for (vector, member) in vectors.vectors.iter().zip(members.iter()) {
+			let signature = Decode::decode(&mut &vector.signature[..]).unwrap();
+			assert!(BandersnatchVrfVerifiable9::verify_signature(
+				&signature,
+				&vector.message,
+				member
+			));
+
+			let proof = Decode::decode(&mut &vector.proof[..]).unwrap();
+			assert_eq!(
+				BandersnatchVrfVerifiable9::validate(&proof, &ring, CONTEXT, &vector.message),
+				Ok(vector.alias)
+			);
+
+			// Proofs are randomized, but aliases are not.
+			let secret = BandersnatchVrfVerifiable9::new_secret(vector.entropy);
+			let commitment =
+				BandersnatchVrfVerifiable9::open(member, members.iter().cloned()).unwrap();
+			let (_, alias) =
+				BandersnatchVrfVerifiable9::create(commitment, &secret, CONTEXT, &vector.message)
+					.unwrap();
+			assert_eq!(alias, vector.alias);
+		}
+	}
+
+	#[test]
+	fn conformance() {
+		init_params();
+		crate::testing::conformance::<BandersnatchVrfVerifiable9>(lookup_range);
+	}
+
+	#[test]
+	fn alias_links() {
+		init_params();
+		let fixture = crate::testing::Fixture::<BandersnatchVrfVerifiable9>::new(lookup_range);
+		crate::testing::check_alias_links(&fixture);
+	}
+
+	#[test]
+	fn alias_ownership() {
+		init_params();
+		let fixture = crate::testing::Fixture::<BandersnatchVrfVerifiable9>::new(lookup_range);
+		crate::testing::check_alias_ownership(&fixture);
+	}
+
 	#[test]
 	fn test_plain_signature() {
 		let msg = b"asd";
-		let secret = BandersnatchVrfVerifiable::new_secret([0; 32]);
-		let public = BandersnatchVrfVerifiable::member_from_secret(&secret);
-		let signature = BandersnatchVrfVerifiable::sign(&secret, msg).unwrap();
-		let res = BandersnatchVrfVerifiable::verify_signature(&signature, msg, &public);
+		let secret = BandersnatchVrfVerifiable9::new_secret([0; 32]);
+		let public = BandersnatchVrfVerifiable9::member_from_secret(&secret);
+		let signature = BandersnatchVrfVerifiable9::sign(&secret, msg).unwrap();
+		let res = BandersnatchVrfVerifiable9::verify_signature(&signature, msg, &public);
 		assert!(res);
 	}
 
+	#[test]
+	fn malformed_input_is_rejected() {
+		let msg = b"asd";
+		let secret = BandersnatchVrfVerifiable9::new_secret([0; 32]);
+		let public = BandersnatchVrfVerifiable9::member_from_secret(&secret);
+		for raw in [[0x00; THIN_SIGNATURE_SIZE], [0xff; THIN_SIGNATURE_SIZE]] {
+			assert!(!BandersnatchVrfVerifiable9::verify_signature(
+				&raw, msg, &public
+			));
+		}
+
+		let members = build_ring(&[public]);
+		for raw in [[0x00; RING_SIGNATURE_SIZE], [0xff; RING_SIGNATURE_SIZE]] {
+			assert!(BandersnatchVrfVerifiable9::validate(&raw, &members, b"ctx", msg).is_err());
+		}
+	}
+
+	#[test]
+	fn open_at_matches_open() {
+		init_params();
+		let (_, members) = members_of(10);
+		let member = members[7].clone();
+		let commitment =
+			BandersnatchVrfVerifiable9::open(&member, members.clone().into_iter()).unwrap();
+
+		for page_size in [1, 3, 7, 10, 64] {
+			let pages = || members.chunks(page_size).map(|page| page.to_vec());
+			let paged = BandersnatchVrfVerifiable9::open_at(&member, 7, pages()).unwrap();
+			assert_eq!(commitment.encode(), paged.encode());
+
+			let mut ring_keys = Vec::new();
+			BandersnatchVrfVerifiable9::write_ring_keys(&member, 7, pages(), &mut ring_keys)
+				.unwrap();
+			assert_eq!(ring_keys.len(), 4 + 32 * members.len());
+			let expanded = BandersnatchVrfVerifiable9::open_ring_keys(&ring_keys).unwrap();
+			assert_eq!(commitment.encode(), expanded.encode());
+
+			assert_eq!(
+				BandersnatchVrfVerifiable9::open_at(&member, 6, pages()).err(),
+				Some(VerifiableError::NotMember)
+			);
+			assert_eq!(
+				BandersnatchVrfVerifiable9::open_at(&member, 10, pages()).err(),
+				Some(VerifiableError::MemberIndexOutOfRange)
+			);
+			assert_eq!(
+				BandersnatchVrfVerifiable9::write_ring_keys(&member, 6, pages(), &mut Vec::new()),
+				Err(VerifiableError::NotMember)
+			);
+			assert_eq!(
+				BandersnatchVrfVerifiable9::write_ring_keys(&member, 10, pages(), &mut Vec::new()),
+				Err(VerifiableError::MemberIndexOutOfRange)
+			);
+		}
+
+		let mut ring_keys = Vec::new();
+		BandersnatchVrfVerifiable9::write_ring_keys(
+			&member,
+			7,
+			members.chunks(4).map(|page| page.to_vec()),
+			&mut ring_keys,
+		)
+		.unwrap();
+		assert!(
+			BandersnatchVrfVerifiable9::open_ring_keys(&ring_keys[..ring_keys.len() - 1]).is_err()
+		);
+		ring_keys[..4].copy_from_slice(&10u32.to_le_bytes());
+		assert_eq!(
+			BandersnatchVrfVerifiable9::open_ring_keys(&ring_keys).err(),
+			Some(VerifiableError::MemberIndexOutOfRange)
+		);
+
+		let capacity = BandersnatchVrfVerifiable9::capacity();
+		let mut oversized = 0u32.to_le_bytes().to_vec();
+		for _ in 0..=capacity {
+			oversized.extend(member.encode());
+		}
+		assert_eq!(
+			BandersnatchVrfVerifiable9::open_ring_keys(&oversized).err(),
+			Some(VerifiableError::RingFull)
+		);
+		let full = (0..=capacity).map(|_| member.clone()).collect::<Vec<_>>();
+		assert_eq!(
+			BandersnatchVrfVerifiable9::write_ring_keys(
+				&member,
+				0,
+				full.chunks(64).map(|page| page.to_vec()),
+				&mut Vec::new()
+			),
+			Err(VerifiableError::RingFull)
+		);
+	}
+
+	#[test]
+	fn prover_handle_works() {
+		init_params();
+		let (secrets, members, ring) = ring_of(10);
+		let (secret, member) = (&secrets[4], members[4].clone());
+
+		let commitment =
+			BandersnatchVrfVerifiable9::open(&member, members.clone().into_iter()).unwrap();
+		let saved = RingProverHandle::save(&commitment, &ring);
+		let handle = RingProverHandle::new(commitment, ring.clone()).unwrap();
+
+		for (context, message) in [
+			(&b"ctx1"[..], &b"foo"[..]),
+			(b"ctx2", b"bar"),
+			(b"ctx1", b"baz"),
+		] {
+			let (proof, alias) = handle.create(secret, context, message).unwrap();
+			assert_eq!(
+				BandersnatchVrfVerifiable9::validate(&proof, &ring, context, message),
+				Ok(alias)
+			);
+		}
+
+		let reloaded = RingProverHandle::load(&saved, &ring).unwrap();
+		assert_eq!(reloaded.member_idx(), 4);
+		let (proof, alias) = reloaded.create(secret, b"ctx3", b"qux").unwrap();
+		assert_eq!(
+			BandersnatchVrfVerifiable9::validate(&proof, &ring, b"ctx3", b"qux"),
+			Ok(alias)
+		);
+
+		let other_ring = build_ring(&members[..5]);
+		assert_eq!(
+			RingProverHandle::load(&saved, &other_ring).err(),
+			Some(VerifiableError::MembersMismatch)
+		);
+	}
+
+	#[test]
+	fn multi_context_proofs_work() {
+		init_params();
+		let (secrets, members, ring) = ring_of(10);
+
+		let commitment =
+			BandersnatchVrfVerifiable9::open(&members[3], members.clone().into_iter()).unwrap();
+		let handle = RingProverHandle::new(commitment, ring.clone()).unwrap();
+		let (_, alias_x) = handle.create(&secrets[3], b"X", b"msg").unwrap();
+		let (_, alias_y) = handle.create(&secrets[3], b"Y", b"msg").unwrap();
+
+		let (proof, aliases) = handle
+			.create_multi(&secrets[3], [&b"X"[..], &b"Y"[..]], b"migrate")
+			.unwrap();
+		assert_eq!(aliases, [alias_x, alias_y]);
+		assert_eq!(
+			BandersnatchVrfVerifiable9::validate_multi(
+				&proof,
+				&ring,
+				[&b"X"[..], b"Y"],
+				b"migrate"
+			),
+			Ok([alias_x, alias_y])
+		);
+		assert!(BandersnatchVrfVerifiable9::validate_multi(
+			&proof,
+			&ring,
+			[&b"Y"[..], b"X"],
+			b"migrate"
+		)
+		.is_err());
+		assert!(BandersnatchVrfVerifiable9::validate_multi(
+			&proof,
+			&ring,
+			[&b"X"[..], b"Y"],
+			b"other"
+		)
+		.is_err());
+		assert_eq!(
+			BandersnatchVrfVerifiable9::validate_multi(&proof, &ring, [&b"X"[..]], b"migrate"),
+			Err(VerifiableError::MalformedProof)
+		);
+
+		// A single-context multi proof is an ordinary proof.
+		let commitment =
+			BandersnatchVrfVerifiable9::open(&members[3], members.clone().into_iter()).unwrap();
+		let (proof, [alias]) =
+			BandersnatchVrfVerifiable9::create_multi(commitment, &secrets[3], [&b"X"[..]], b"msg")
+				.unwrap();
+		assert_eq!(alias, alias_x);
+		let proof: [u8; RING_SIGNATURE_SIZE] = proof.try_into().unwrap();
+		assert_eq!(
+			BandersnatchVrfVerifiable9::validate(&proof, &ring, b"X", b"msg"),
+			Ok(alias_x)
+		);
+	}
+
+	#[test]
+	fn prepared_members_works() {
+		init_params();
+		let (secrets, members, ring) = ring_of(10);
+
+		let commitment =
+			BandersnatchVrfVerifiable9::open(&members[5], members.clone().into_iter()).unwrap();
+		let (proof, alias) =
+			BandersnatchVrfVerifiable9::create(commitment, &secrets[5], b"ctx", b"msg").unwrap();
+
+		let prepared = PreparedMembers::new(ring.clone()).unwrap();
+		assert_eq!(prepared.members(), &ring);
+		assert_eq!(prepared.validate(&proof, b"ctx", b"msg"), Ok(alias));
+		assert!(prepared.is_valid(&proof, b"ctx", &alias, b"msg"));
+		assert!(!prepared.is_valid(&proof, b"ctx", &alias, b"other"));
+		assert!(!prepared.is_valid(&proof, b"other", &alias, b"msg"));
+	}
+
 	#[test]
 	fn open_validate_works() {
 		use std::time::Instant;
@@ -354,35 +1969,27 @@ mod tests {
 		let message = b"FooBar";
 
 		let start = Instant::now();
-		let _ = kzg();
+		init_params();
 		println!("* KZG decode: {} ms", (Instant::now() - start).as_millis());
 
-		let members: Vec<_> = (0..10)
-			.map(|i| {
-				let secret = BandersnatchVrfVerifiable::new_secret([i as u8; 32]);
-				BandersnatchVrfVerifiable::member_from_secret(&secret)
-			})
-			.collect();
+		let (secrets, members) = members_of(10);
 		let member = members[3].clone();
 
 		let start = Instant::now();
 		let commitment =
-			BandersnatchVrfVerifiable::open(&member, members.clone().into_iter()).unwrap();
+			BandersnatchVrfVerifiable9::open(&member, members.clone().into_iter()).unwrap();
 		println!("* Open: {} ms", (Instant::now() - start).as_millis());
 		println!("  Commitment size: {} bytes", commitment.encode().len()); // ~49 MB
 
-		let secret = BandersnatchVrfVerifiable::new_secret([commitment.0 as u8; 32]);
+		let secret = &secrets[commitment.0 as usize];
 		let start = Instant::now();
 		let (proof, alias) =
-			BandersnatchVrfVerifiable::create(commitment, &secret, context, message).unwrap();
+			BandersnatchVrfVerifiable9::create(commitment, secret, context, message).unwrap();
 		println!("* Create: {} ms", (Instant::now() - start).as_millis());
 		println!("  Proof size: {} bytes", proof.encode().len()); // 788 bytes
 
-		let vk = StaticVerifierKey::deserialize_uncompressed_unchecked(ONCHAIN_VK).unwrap();
-		let get_one = |i| Ok(ArkScale(vk.lag_g1[i]));
-
 		let start = Instant::now();
-		let mut inter = BandersnatchVrfVerifiable::start_members();
+		let mut inter = BandersnatchVrfVerifiable9::start_members();
 		println!(
 			"* Start members: {} ms",
 			(Instant::now() - start).as_millis()
@@ -390,7 +1997,7 @@ mod tests {
 
 		let start = Instant::now();
 		members.iter().for_each(|member| {
-			BandersnatchVrfVerifiable::push_member(&mut inter, member.clone(), get_one).unwrap();
+			BandersnatchVrfVerifiable9::push_member(&mut inter, member.clone(), lookup).unwrap();
 		});
 		println!(
 			"* Push {} members: {} ms",
@@ -399,7 +2006,7 @@ mod tests {
 		);
 
 		let start = Instant::now();
-		let members = BandersnatchVrfVerifiable::finish_members(inter);
+		let members = BandersnatchVrfVerifiable9::finish_members(inter);
 		println!(
 			"* Finish members: {} ms",
 			(Instant::now() - start).as_millis()
@@ -407,7 +2014,7 @@ mod tests {
 
 		let start = Instant::now();
 		let alias2 =
-			BandersnatchVrfVerifiable::validate(&proof, &members, context, message).unwrap();
+			BandersnatchVrfVerifiable9::validate(&proof, &members, context, message).unwrap();
 		println!("* Validate {} ms", (Instant::now() - start).as_millis());
 		assert_eq!(alias, alias2);
 	}
@@ -0,0 +1,264 @@
+//! Generation of ring parameters (SRS) for private deployments which do not want to depend on
+//! the Zcash powers of tau, and a consistency check of such parameters.
+//!
+//! The parameters are laid out like the `zcash-*.pk`/`zcash-*.vk` files in `ring-data`, with all
+//! of the points uncompressed:
+//! - the prover key holds the `3 * 2^log_domain + 1` powers of tau in G1, then the `KzgVk`;
+//! - the verifier key holds the `2^log_domain` Lagrange basis commitments in G1, then the `KzgVk`.
+//!
+//! The prover key is loaded with `RingParams::from_bytes_with_vk`, the verifier key serves the
+//! `StaticChunk`s through `LagrangeSrsProvider`, and rings start from `empty_members` rather than
+//! `start_members`.
+
+use ark_ec::{AffineRepr, CurveGroup, Group};
+use ark_ff::UniformRand;
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use bandersnatch_vrfs::bls12_381::{Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use bandersnatch_vrfs::ring::KzgVk;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use super::*;
+use ring_vrf_impl::{
+	check_powers, BandersnatchVrfVerifiable16, BandersnatchVrfVerifiable9, MembersSet, LOG_DOMAINS,
+};
+
+/// Ring parameters for the domain of size `2^log_domain`.
+pub struct Srs {
+	log_domain: u32,
+	/// The powers of tau in G1, from `tau^0` to `tau^(3 * 2^log_domain)`.
+	powers: Vec<G1Affine>,
+	/// The commitments to the Lagrange basis of the domain in G1.
+	lagrange: Vec<G1Affine>,
+	kzg_vk: KzgVk,
+}
+
+impl Srs {
+	/// Parameters from a tau derived from `seed`.
+	///
+	/// This is for testing only: anyone who knows the seed can forge proofs.
+	pub fn from_seed(log_domain: u32, seed: [u8; 32]) -> Result<Self, VerifiableError> {
+		let count = power_count(log_domain)?;
+		let tau = Fr::rand(&mut ChaCha20Rng::from_seed(seed));
+		let mut powers = Vec::with_capacity(count);
+		let mut power = G1Projective::generator();
+		for _ in 0..count {
+			powers.push(power);
+			power *= tau;
+		}
+		let g2 = G2Projective::generator();
+		Self::from_powers(
+			log_domain,
+			G1Projective::normalize_batch(&powers),
+			g2.into_affine(),
+			(g2 * tau).into_affine(),
+		)
+	}
+
+	/// Parameters from the powers of tau of a ceremony: `powers` in G1 (of which the first
+	/// `3 * 2^log_domain + 1` are used) and the generator `g2` and `tau_in_g2` in G2.
+	pub fn from_powers(
+		log_domain: u32,
+		mut powers: Vec<G1Affine>,
+		g2: G2Affine,
+		tau_in_g2: G2Affine,
+	) -> Result<Self, VerifiableError> {
+		let count = power_count(log_domain)?;
+		if powers.len() < count {
+			return Err(VerifiableError::InvalidParams);
+		}
+		powers.truncate(count);
+		let kzg_vk = KzgVk {
+			g1: powers[0],
+			g2,
+			tau_in_g2,
+		};
+		let lagrange = lagrange_basis(log_domain, &powers);
+		Ok(Self {
+			log_domain,
+			powers,
+			lagrange,
+			kzg_vk,
+		})
+	}
+
+	/// Parameters from a ceremony transcript: the powers of tau in G1 (as a `Vec`), the
+	/// generator in G2 and tau in G2, serialized uncompressed.
+	///
+	/// Transcripts of other ceremonies must be converted to this layout first.
+	pub fn from_transcript(
+		log_domain: u32,
+		mut transcript: &[u8],
+	) -> Result<Self, VerifiableError> {
+		let powers = Vec::<G1Affine>::deserialize_uncompressed(&mut transcript)
+			.map_err(|_| VerifiableError::InvalidParams)?;
+		let g2 = G2Affine::deserialize_uncompressed(&mut transcript)
+			.map_err(|_| VerifiableError::InvalidParams)?;
+		let tau_in_g2 = G2Affine::deserialize_uncompressed(&mut transcript)
+			.map_err(|_| VerifiableError::InvalidParams)?;
+		if !transcript.is_empty() {
+			return Err(VerifiableError::InvalidParams);
+		}
+		Self::from_powers(log_domain, powers, g2, tau_in_g2)
+	}
+
+	/// The log2 of the size of the ring domain of the parameters.
+	pub fn log_domain(&self) -> u32 {
+		self.log_domain
+	}
+
+	/// The KZG verifier key, to pass to `start_members_from_params`.
+	pub fn kzg_vk(&self) -> &KzgVk {
+		&self.kzg_vk
+	}
+
+	/// The serialized prover key, as loaded by `RingParams`.
+	pub fn prover_key(&self) -> Vec<u8> {
+		serialize(&self.powers, &self.kzg_vk)
+	}
+
+	/// The serialized verifier key, holding the `StaticChunk`s.
+	pub fn verifier_key(&self) -> Vec<u8> {
+		serialize(&self.lagrange, &self.kzg_vk)
+	}
+
+	/// The empty ring of these parameters, from which to build rings instead of from
+	/// `start_members`.
+	pub fn empty_members(&self) -> MembersSet {
+		let srs = |range: Range<usize>| Ok(self.lagrange[range].to_vec());
+		match self.log_domain {
+			9 => BandersnatchVrfVerifiable9::start_members_from_params(self.kzg_vk.clone(), srs),
+			16 => BandersnatchVrfVerifiable16::start_members_from_params(self.kzg_vk.clone(), srs),
+			_ => unreachable!("parameters are of a known domain; qed"),
+		}
+	}
+}
+
+/// The number of powers of tau in G1 needed for the domain of size `2^log_domain`.
+fn power_count(log_domain: u32) -> Result<usize, VerifiableError> {
+	if !LOG_DOMAINS.contains(&log_domain) {
+		return Err(VerifiableError::DomainMismatch);
+	}
+	Ok(3 * (1 << log_domain) + 1)
+}
+
+/// The commitments to the Lagrange basis of the domain of size `2^log_domain`, from the powers of
+/// tau: as `L_i(X) = 1/n * sum_j (w^-i X)^j`, they are the inverse FFT of the first `n` powers.
+fn lagrange_basis(log_domain: u32, powers: &[G1Affine]) -> Vec<G1Affine> {
+	let n = 1 << log_domain;
+	let domain = Radix2EvaluationDomain::<Fr>::new(n).expect("BLS12-381 has 2-adicity 32; qed");
+	let mut lagrange: Vec<_> = powers[..n].iter().map(|p| p.into_group()).collect();
+	domain.ifft_in_place(&mut lagrange);
+	G1Projective::normalize_batch(&lagrange)
+}
+
+fn serialize(points: &[G1Affine], kzg_vk: &KzgVk) -> Vec<u8> {
+	let mut buf = Vec::with_capacity(points.uncompressed_size() + kzg_vk.uncompressed_size());
+	points
+		.serialize_uncompressed(&mut buf)
+		.expect("serialization into a Vec cannot fail; qed");
+	kzg_vk
+		.serialize_uncompressed(&mut buf)
+		.expect("serialization into a Vec cannot fail; qed");
+	buf
+}
+
+/// Check that the serialized prover key `pk` and verifier key `vk` are consistent, returning the
+/// log2 of the size of their domain.
+///
+/// All of the points must be valid (on the curve and in the subgroup), both keys must hold the
+/// same `KzgVk`, whose tau in G2 must match the powers of tau in G1 of the prover key, and the
+/// verifier key must hold the Lagrange basis of those powers of tau.
+pub fn check(mut pk: &[u8], mut vk: &[u8]) -> Result<u32, VerifiableError> {
+	let original_pk = pk;
+	let powers = Vec::<G1Affine>::deserialize_uncompressed(&mut pk)
+		.map_err(|_| VerifiableError::InvalidParams)?;
+	let lagrange = Vec::<G1Affine>::deserialize_uncompressed(&mut vk)
+		.map_err(|_| VerifiableError::InvalidParams)?;
+	// Both keys end with the same `KzgVk`.
+	if pk != vk {
+		return Err(VerifiableError::InvalidParams);
+	}
+	let kzg_vk =
+		KzgVk::deserialize_uncompressed(&mut pk).map_err(|_| VerifiableError::InvalidParams)?;
+	if !pk.is_empty() {
+		return Err(VerifiableError::InvalidParams);
+	}
+
+	let log_domain = LOG_DOMAINS
+		.into_iter()
+		.find(|&log_domain| {
+			power_count(log_domain) == Ok(powers.len()) && lagrange.len() == 1 << log_domain
+		})
+		.ok_or(VerifiableError::DomainMismatch)?;
+
+	check_powers(&powers, &kzg_vk, original_pk)?;
+	if lagrange_basis(log_domain, &powers) != lagrange {
+		return Err(VerifiableError::InvalidParams);
+	}
+	Ok(log_domain)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ring_vrf_impl::RingParams;
+
+	const ZCASH_PK: &[u8] = include_bytes!("ring-data/zcash-9.pk");
+	const ZCASH_VK: &[u8] = include_bytes!("ring-data/zcash-9.vk");
+
+	#[test]
+	fn zcash_params_are_consistent() {
+		assert_eq!(check(ZCASH_PK, ZCASH_VK), Ok(9));
+	}
+
+	#[test]
+	fn seeded_params_are_consistent() {
+		let srs = Srs::from_seed(9, [1u8; 32]).unwrap();
+		let (pk, vk) = (srs.prover_key(), srs.verifier_key());
+		assert_eq!((pk.len(), vk.len()), (ZCASH_PK.len(), ZCASH_VK.len()));
+		assert_eq!(check(&pk, &vk), Ok(9));
+		assert_eq!(
+			RingParams::from_bytes_with_vk(&pk, srs.kzg_vk())
+				.unwrap()
+				.log_domain(),
+			9
+		);
+		// They are not the Zcash parameters.
+		assert!(RingParams::from_bytes(&pk).is_err());
+		assert_eq!(srs.empty_members().log_domain, 9);
+
+		// Keys of different setups do not match.
+		let other = Srs::from_seed(9, [2u8; 32]).unwrap();
+		assert!(check(&pk, &other.verifier_key()).is_err());
+		assert!(check(&pk, ZCASH_VK).is_err());
+		assert!(check(&pk[..pk.len() - 1], &vk).is_err());
+	}
+
+	#[test]
+	fn transcript_params_match() {
+		let srs = Srs::from_seed(9, [1u8; 32]).unwrap();
+		let mut transcript = Vec::new();
+		srs.powers.serialize_uncompressed(&mut transcript).unwrap();
+		srs.kzg_vk
+			.g2
+			.serialize_uncompressed(&mut transcript)
+			.unwrap();
+		srs.kzg_vk
+			.tau_in_g2
+			.serialize_uncompressed(&mut transcript)
+			.unwrap();
+		let imported = Srs::from_transcript(9, &transcript).unwrap();
+		assert_eq!(imported.prover_key(), srs.prover_key());
+		assert_eq!(imported.verifier_key(), srs.verifier_key());
+
+		assert_eq!(
+			Srs::from_transcript(16, &transcript).err(),
+			Some(VerifiableError::InvalidParams)
+		);
+		assert_eq!(
+			Srs::from_transcript(10, &transcript).err(),
+			Some(VerifiableError::DomainMismatch)
+		);
+	}
+}
@@ -0,0 +1,297 @@
+//! Generic conformance checks for implementations of `GenerateVerifiable`.
+//!
+//! Backends outside of this crate may run these in their own tests (with the `testing` feature)
+//! to make sure they behave like the in-tree implementations. Each `check_*` function panics on
+//! failure; `conformance` runs all of them.
+
+use super::*;
+
+const CONTEXT: &[u8] = b"ConformanceContext";
+const OTHER_CONTEXT: &[u8] = b"ConformanceOtherContext";
+const MESSAGE: &[u8] = b"Conformance message";
+const OTHER_MESSAGE: &[u8] = b"Conformance other message";
+
+/// Number of members in the ring of a `Fixture`.
+pub const MEMBERS: usize = 4;
+
+/// Keys and rings shared by the conformance checks.
+pub struct Fixture<T: GenerateVerifiable> {
+	/// Secrets of the ring's members.
+	pub secrets: Vec<T::Secret>,
+	/// The ring's members, in order.
+	pub members: Vec<T::Member>,
+	/// The intermediate value from which `ring` was finished.
+	pub intermediate: T::Intermediate,
+	/// The ring of all of `members`.
+	pub ring: T::Members,
+	/// A ring of all of `members` but the first one.
+	pub other_ring: T::Members,
+	/// Secret of someone who is not in `ring`.
+	pub outsider: T::Secret,
+	/// Encoded commitments of each of `members` in `ring`, since opening may be expensive.
+	commitments: Vec<Vec<u8>>,
+}
+
+impl<T: GenerateVerifiable> Fixture<T> {
+	/// Build the fixture, using `lookup` to introduce members.
+	pub fn new(lookup: impl Fn(Range<usize>) -> Result<Vec<T::StaticChunk>, ()>) -> Self {
+		let secrets: Vec<_> = (1..=MEMBERS as u8)
+			.map(|i| T::new_secret([i; 32]))
+			.collect();
+		let members: Vec<_> = secrets.iter().map(T::member_from_secret).collect();
+		let outsider = T::new_secret([0xee; 32]);
+
+		let mut intermediate = T::start_members();
+		T::push_members(&mut intermediate, members.iter().cloned(), &lookup)
+			.expect("members can be pushed");
+		let ring = T::finish_members(intermediate.clone());
+
+		let mut other = T::start_members();
+		T::push_members(&mut other, members[1..].iter().cloned(), &lookup)
+			.expect("members can be pushed");
+		let other_ring = T::finish_members(other);
+
+		let commitments = members
+			.iter()
+			.map(|m| {
+				T::open(m, members.iter().cloned())
+					.expect("members can open")
+					.encode()
+			})
+			.collect();
+
+		Self {
+			secrets,
+			members,
+			intermediate,
+			ring,
+			other_ring,
+			outsider,
+			commitments,
+		}
+	}
+
+	/// The commitment of member `i` in `ring`.
+	pub fn commitment(&self, i: usize) -> T::Commitment {
+		T::Commitment::decode(&mut &self.commitments[i][..]).expect("commitments round-trip")
+	}
+
+	/// A proof of member `i` in `ring`.
+	pub fn create(&self, i: usize, context: &[u8], message: &[u8]) -> (T::Proof, Alias) {
+		T::create(self.commitment(i), &self.secrets[i], context, message)
+			.expect("members can create proofs")
+	}
+}
+
+/// Run all of the checks against `T`.
+pub fn conformance<T: GenerateVerifiable>(
+	lookup: impl Fn(Range<usize>) -> Result<Vec<T::StaticChunk>, ()>,
+) {
+	let fixture = Fixture::<T>::new(&lookup);
+	check_round_trip(&fixture);
+	check_non_membership(&fixture);
+	check_context_unlinkability(&fixture);
+	check_alias_stability(&fixture);
+	check_message_binding(&fixture);
+	check_context_binding(&fixture);
+	check_wrong_ring(&fixture);
+	check_signatures(&fixture);
+	check_codec(&fixture, &lookup);
+}
+
+/// Every member can create a proof which validates to its alias.
+pub fn check_round_trip<T: GenerateVerifiable>(f: &Fixture<T>) {
+	for i in 0..MEMBERS {
+		let (proof, alias) = f.create(i, CONTEXT, MESSAGE);
+		assert_eq!(
+			T::validate(&proof, &f.ring, CONTEXT, MESSAGE),
+			Ok(alias),
+			"proof of member {i} does not validate"
+		);
+		assert!(T::is_valid(&proof, &f.ring, CONTEXT, &alias, MESSAGE));
+	}
+}
+
+/// Someone who is not a member can neither open nor create a valid proof.
+pub fn check_non_membership<T: GenerateVerifiable>(f: &Fixture<T>) {
+	let outsider = T::member_from_secret(&f.outsider);
+	assert!(
+		T::open(&outsider, f.members.iter().cloned()).is_err(),
+		"non-member can open"
+	);
+	if let Ok((proof, _)) = T::create(f.commitment(0), &f.outsider, CONTEXT, MESSAGE) {
+		assert!(
+			T::validate(&proof, &f.ring, CONTEXT, MESSAGE).is_err(),
+			"non-member can create a valid proof with a member's commitment"
+		);
+	}
+}
+
+/// Aliases differ between contexts and between members.
+pub fn check_context_unlinkability<T: GenerateVerifiable>(f: &Fixture<T>) {
+	let (_, alias) = f.create(0, CONTEXT, MESSAGE);
+	let (_, other_context_alias) = f.create(0, OTHER_CONTEXT, MESSAGE);
+	let (_, other_member_alias) = f.create(1, CONTEXT, MESSAGE);
+	assert_ne!(
+		alias, other_context_alias,
+		"alias is the same in different contexts"
+	);
+	assert_ne!(
+		alias, other_member_alias,
+		"alias is the same for different members"
+	);
+}
+
+/// A member's alias in a context does not depend on the message.
+pub fn check_alias_stability<T: GenerateVerifiable>(f: &Fixture<T>) {
+	let (_, alias) = f.create(0, CONTEXT, MESSAGE);
+	let (_, other_alias) = f.create(0, CONTEXT, OTHER_MESSAGE);
+	assert_eq!(alias, other_alias, "alias depends on the message");
+}
+
+/// A proof does not validate for another message.
+pub fn check_message_binding<T: GenerateVerifiable>(f: &Fixture<T>) {
+	let (proof, alias) = f.create(0, CONTEXT, MESSAGE);
+	assert!(
+		!T::is_valid(&proof, &f.ring, CONTEXT, &alias, OTHER_MESSAGE),
+		"proof is valid for another message"
+	);
+	assert!(T::validate(&proof, &f.ring, CONTEXT, OTHER_MESSAGE).is_err());
+}
+
+/// A proof does not validate in another context.
+pub fn check_context_binding<T: GenerateVerifiable>(f: &Fixture<T>) {
+	let (proof, alias) = f.create(0, CONTEXT, MESSAGE);
+	assert!(
+		!T::is_valid(&proof, &f.ring, OTHER_CONTEXT, &alias, MESSAGE),
+		"proof is valid in another context"
+	);
+	assert!(T::validate(&proof, &f.ring, OTHER_CONTEXT, MESSAGE).is_err());
+}
+
+/// A proof does not validate against a ring which does not include the member.
+pub fn check_wrong_ring<T: GenerateVerifiable>(f: &Fixture<T>) {
+	let (proof, _) = f.create(0, CONTEXT, MESSAGE);
+	assert!(
+		T::validate(&proof, &f.other_ring, CONTEXT, MESSAGE).is_err(),
+		"proof is valid against a ring without the member"
+	);
+}
+
+/// Signatures, if supported, verify for the signer and message only.
+pub fn check_signatures<T: GenerateVerifiable>(f: &Fixture<T>) {
+	let signature = match T::sign(&f.secrets[0], MESSAGE) {
+		Ok(signature) => signature,
+		Err(VerifiableError::Unsupported) => return,
+		Err(e) => panic!("signing failed: {e:?}"),
+	};
+	assert!(T::verify_signature(&signature, MESSAGE, &f.members[0]));
+	assert!(!T::verify_signature(
+		&signature,
+		OTHER_MESSAGE,
+		&f.members[0]
+	));
+	assert!(!T::verify_signature(&signature, MESSAGE, &f.members[1]));
+}
+
+/// Every associated type survives an encoding round-trip.
+pub fn check_codec<T: GenerateVerifiable>(
+	f: &Fixture<T>,
+	lookup: impl Fn(Range<usize>) -> Result<Vec<T::StaticChunk>, ()>,
+) {
+	fn round_trip<V: FullCodec + PartialEq + Debug>(value: &V) {
+		let encoded = value.encode();
+		assert_eq!(&V::decode(&mut &encoded[..]).expect("value decodes"), value);
+	}
+
+	round_trip(&f.ring);
+	round_trip(&f.intermediate);
+	round_trip(&f.members[0]);
+	let (proof, _) = f.create(0, CONTEXT, MESSAGE);
+	round_trip(&proof);
+	if let Ok(signature) = T::sign(&f.secrets[0], MESSAGE) {
+		round_trip(&signature);
+	}
+	for chunk in lookup(0..1).expect("lookup works") {
+		round_trip(&chunk);
+	}
+	// Commitments are neither `Eq` nor `Debug`, so compare their encodings.
+	let encoded = f.commitment(0).encode();
+	let decoded = T::Commitment::decode(&mut &encoded[..]).expect("commitment decodes");
+	assert_eq!(decoded.encode(), encoded);
+}
+
+/// A link proof, for implementations of `LinkableAliases`, verifies for the two aliases of its
+/// member only.
+pub fn check_alias_links<T: LinkableAliases>(f: &Fixture<T>) {
+	let (_, alias_a) = f.create(0, CONTEXT, MESSAGE);
+	let (_, alias_b) = f.create(0, OTHER_CONTEXT, MESSAGE);
+	let (_, other_alias) = f.create(1, OTHER_CONTEXT, MESSAGE);
+	let (proof, a, b) = T::prove_alias_link(f.commitment(0), &f.secrets[0], CONTEXT, OTHER_CONTEXT)
+		.expect("members can link aliases");
+	assert_eq!((a, b), (alias_a, alias_b), "link is for other aliases");
+
+	assert!(T::verify_alias_link(
+		&proof,
+		&f.ring,
+		CONTEXT,
+		&alias_a,
+		OTHER_CONTEXT,
+		&alias_b
+	));
+	assert!(
+		!T::verify_alias_link(
+			&proof,
+			&f.ring,
+			CONTEXT,
+			&alias_a,
+			OTHER_CONTEXT,
+			&other_alias
+		),
+		"link is valid for another member's alias"
+	);
+	assert!(
+		!T::verify_alias_link(&proof, &f.ring, OTHER_CONTEXT, &alias_b, CONTEXT, &alias_a),
+		"link is valid with the contexts swapped"
+	);
+	assert!(
+		!T::verify_alias_link(
+			&proof,
+			&f.other_ring,
+			CONTEXT,
+			&alias_a,
+			OTHER_CONTEXT,
+			&alias_b
+		),
+		"link is valid against a ring without the member"
+	);
+}
+
+/// An ownership proof, for implementations of `AliasOwnership`, verifies for its member, context
+/// and alias only.
+pub fn check_alias_ownership<T: AliasOwnership>(f: &Fixture<T>) {
+	let (_, alias) = f.create(0, CONTEXT, MESSAGE);
+	let (_, other_alias) = f.create(1, CONTEXT, MESSAGE);
+	let (proof, owned) =
+		T::prove_alias_ownership(&f.secrets[0], CONTEXT).expect("members can prove ownership");
+	assert_eq!(owned, alias, "ownership is of another alias");
+
+	assert!(T::verify_alias_ownership(
+		&proof,
+		&f.members[0],
+		CONTEXT,
+		&alias
+	));
+	assert!(
+		!T::verify_alias_ownership(&proof, &f.members[1], CONTEXT, &alias),
+		"ownership is valid for another member"
+	);
+	assert!(
+		!T::verify_alias_ownership(&proof, &f.members[0], CONTEXT, &other_alias),
+		"ownership is valid for another alias"
+	);
+	assert!(
+		!T::verify_alias_ownership(&proof, &f.members[0], OTHER_CONTEXT, &alias),
+		"ownership is valid in another context"
+	);
+}